@@ -1,5 +1,4 @@
 use crate::style::Tile;
-use crate::CharMatch;
 use crate::Message;
 use iced::button;
 use iced::Align;
@@ -9,7 +8,7 @@ use iced::Element;
 use iced::Length;
 use iced::Row;
 use iced::Text;
-use jordle::logic::types::WordMatch;
+use jordle::logic::KeyboardState;
 
 pub struct Keyboard {
     rows: Vec<KeyboardRow>,
@@ -40,13 +39,9 @@ impl Keyboard {
         col.into()
     }
 
-    pub fn update(&mut self, c: &WordMatch) {
-        for tc in c.char_matches() {
-            for row in &mut self.rows {
-                if row.update(&tc) {
-                    break;
-                }
-            }
+    pub fn update(&mut self, state: &KeyboardState) {
+        for row in &mut self.rows {
+            row.update(state);
         }
     }
 }
@@ -91,16 +86,9 @@ impl KeyboardRow {
         row.into()
     }
 
-    pub fn update(&mut self, cmatch: &CharMatch) -> bool {
+    pub fn update(&mut self, state: &KeyboardState) {
         for (c, tile, _) in &mut self.row {
-            if *c == cmatch.c {
-                match tile {
-                    Tile::Correct => {}
-                    _ => *tile = cmatch.align.into(),
-                }
-                return true;
-            }
+            *tile = state.status(*c).into();
         }
-        return false;
     }
 }
@@ -0,0 +1,13 @@
+const CHALLENGE_PARAM: &str = "challenge";
+
+pub fn challenge_code() -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|x| x.split_once('='))
+        .find(|(key, _)| *key == CHALLENGE_PARAM)
+        .map(|(_, value)| value.to_string())
+        .filter(|x| !x.is_empty())
+}
@@ -1,4 +1,6 @@
 mod keyboard;
+mod location;
+mod storage;
 mod style;
 
 use iced::{
@@ -6,8 +8,9 @@ use iced::{
     Sandbox, Settings, Space, Text, TextInput,
 };
 use jordle::logic::{
-    mutator::{NoopMutator, StepProbMutator},
-    CharMatch, GuessResult, WordValidation, Wordle,
+    mutator::{FibbleMutator, MutatorConfig, NoopMutator, StepProbMutator},
+    Absurdle, CharMatch, Game, GameParameters, GameRecord, GameStatus, HintKind, SavedGame,
+    ScoringConfig, Statistics, WordValidation, Wordle,
 };
 use keyboard::Keyboard;
 use style::{Theme, Tile};
@@ -20,17 +23,23 @@ fn main() -> iced::Result {
 pub enum GameVarient {
     Jordle,
     Fuzzle,
+    Fibble,
+    Absurdle,
 }
 
-struct WordleGui<'a> {
-    wordle: Wordle<'a>,
+struct WordleGui {
+    wordle: Box<dyn Game>,
     words: Vec<WordRow>,
     reset_button: button::State,
     next_button: button::State,
+    hint_button: button::State,
+    hint_text: Option<String>,
+    error_text: Option<String>,
     current_varient: GameVarient,
     guess_text: String,
     game_state: GameGuiState,
     keyboard: Keyboard,
+    stats: Statistics,
 }
 
 #[derive(Debug, Clone)]
@@ -44,52 +53,99 @@ pub enum Message {
     TextChanged(String),
     KeyboardButton(char),
     TextSubmitted,
+    RequestHint,
     RestartGame(GameVarient),
 }
 
-impl Default for WordleGui<'static> {
+impl Default for WordleGui {
     fn default() -> Self {
+        WordleGui::with_game(Box::new(Wordle::default()), GameVarient::Jordle)
+    }
+}
+
+impl WordleGui {
+    fn with_game(wordle: Box<dyn Game>, current_varient: GameVarient) -> Self {
         WordleGui {
-            wordle: Wordle::default(),
-            current_varient: GameVarient::Jordle,
+            wordle,
+            current_varient,
             words: vec![],
             game_state: GameGuiState::Running(text_input::State::new()),
             guess_text: String::new(),
             keyboard: Keyboard::new(),
             reset_button: button::State::new(),
             next_button: button::State::new(),
+            hint_button: button::State::new(),
+            hint_text: None,
+            error_text: None,
+            stats: storage::load_stats(),
         }
     }
-}
 
-impl WordleGui<'_> {
     fn restart(&mut self, varient: GameVarient) {
-        let mut wordle = Wordle::default();
+        let mut params = GameParameters::default();
+        let mut rng = rand::thread_rng();
 
-        wordle.params.mutator = match varient {
-            GameVarient::Jordle => Box::new(NoopMutator::default()),
-            GameVarient::Fuzzle => Box::new(StepProbMutator::default()),
+        let wordle: Box<dyn Game> = match varient {
+            GameVarient::Jordle => {
+                params.mutator = Box::new(NoopMutator::default());
+                Box::new(Wordle::new_random_game(params, &mut rng))
+            }
+            GameVarient::Fuzzle => {
+                params.mutator = Box::new(StepProbMutator::default());
+                Box::new(Wordle::new_random_game(params, &mut rng))
+            }
+            GameVarient::Fibble => {
+                params.mutator = Box::new(FibbleMutator::default());
+                Box::new(Wordle::new_random_game(params, &mut rng))
+            }
+            GameVarient::Absurdle => Box::new(Absurdle::new_game(params)),
         };
 
-        *self = WordleGui {
-            wordle,
-            current_varient: varient,
-            ..WordleGui::default()
+        storage::clear_game();
+
+        *self = WordleGui::with_game(wordle, varient);
+    }
+
+    fn resume(saved: &SavedGame) -> Self {
+        let wordle = Wordle::resume(saved);
+        let current_varient = GameVarient::from_mutator(&saved.preset.mutator);
+
+        let mut gui = WordleGui::with_game(Box::new(wordle), current_varient);
+
+        gui.keyboard.update(&gui.wordle.state().keyboard);
+        for matches in saved.displayed_rows().iter() {
+            gui.words
+                .push(WordRow::new(matches.char_matches().collect()));
         }
+
+        gui
     }
 }
 
-impl Sandbox for WordleGui<'_> {
+impl Sandbox for WordleGui {
     type Message = Message;
 
     fn new() -> Self {
-        let wordle = Wordle::default();
+        let mut error_text = None;
 
-        WordleGui {
-            wordle,
-            current_varient: GameVarient::Jordle,
-            ..WordleGui::default()
+        if let Some(code) = location::challenge_code() {
+            match Wordle::from_challenge(&code) {
+                Ok(wordle) => {
+                    storage::clear_game();
+
+                    let varient = GameVarient::from_mutator(&wordle.params.mutator.config());
+                    return WordleGui::with_game(Box::new(wordle), varient);
+                }
+                Err(err) => error_text = Some(format!("Invalid challenge: {}", err)),
+            }
         }
+
+        let gui = match storage::load_game() {
+            Some(saved) => WordleGui::resume(&saved),
+            None => WordleGui::default(),
+        };
+
+        WordleGui { error_text, ..gui }
     }
 
     fn title(&self) -> String {
@@ -107,8 +163,13 @@ impl Sandbox for WordleGui<'_> {
             .align_items(Align::Center)
             .spacing(padding)
             .height(Length::Fill)
-            .push(title)
-            .push(Space::new(Length::Fill, Length::FillPortion(1)));
+            .push(title);
+
+        if let Some(error) = &self.error_text {
+            column = column.push(Text::new(error.as_str()).size(16));
+        }
+
+        column = column.push(Space::new(Length::Fill, Length::FillPortion(1)));
 
         for wordrow in &mut self.words {
             column = column.push(wordrow.view())
@@ -117,7 +178,7 @@ impl Sandbox for WordleGui<'_> {
         let guess_text = format!(
             "{:width$}",
             self.guess_text,
-            width = self.wordle.params.word_size.0
+            width = self.wordle.state().target_word.chars().count()
         );
 
         column = column.push(WordRow::render(
@@ -147,7 +208,12 @@ impl Sandbox for WordleGui<'_> {
             .push(Space::new(Length::Units(4), Length::Fill));
 
         if let GameGuiState::Running(text_state) = &mut self.game_state {
+            let hint_button = Button::new(&mut self.hint_button, Text::new("Hint"))
+                .on_press(Message::RequestHint);
+
             footer = footer
+                .push(hint_button)
+                .push(Space::new(Length::Units(4), Length::Fill))
                 .push(
                     TextInput::new(
                         text_state,
@@ -162,6 +228,30 @@ impl Sandbox for WordleGui<'_> {
 
         footer = footer.push(next_button).width(Length::Shrink);
 
+        if let Some(hint) = &self.hint_text {
+            column = column.push(Text::new(hint.as_str()).size(16));
+        }
+
+        if let GameGuiState::Finished(_) = self.game_state {
+            column = column
+                .push(Space::new(Length::Fill, Length::Units(20)))
+                .push(Text::new(self.stats.to_string()).size(16))
+                .push(
+                    Text::new(
+                        ScoringConfig::default()
+                            .score(self.wordle.as_ref())
+                            .to_string(),
+                    )
+                    .size(16),
+                );
+
+            let lies = self.wordle.reveal_lies().unwrap_or_default();
+            if !lies.is_empty() {
+                let lies: Vec<String> = lies.iter().map(|x| x.to_string()).collect();
+                column = column.push(Text::new(lies.join("\n")).size(16));
+            }
+        }
+
         column = column
             .push(Space::new(Length::Fill, Length::FillPortion(1)))
             .push(footer)
@@ -187,18 +277,40 @@ impl Sandbox for WordleGui<'_> {
             Message::TextSubmitted => {
                 let result = self.wordle.guess(self.guess_text.as_str());
 
-                if let WordValidation::Valid(guess_result, matches) = result {
-                    self.keyboard.update(&matches);
+                if let WordValidation::Valid(_, matches) = result {
+                    self.keyboard.update(&self.wordle.state().keyboard);
                     let row = WordRow::new(matches.char_matches().collect());
                     self.words.push(row);
 
-                    if let GuessResult::Correct = guess_result {
-                        self.game_state = GameGuiState::Finished(button::State::new())
+                    if self.wordle.status() != GameStatus::Playing {
+                        self.game_state = GameGuiState::Finished(button::State::new());
+                        storage::clear_game();
+
+                        let variant = self.current_varient.to_string();
+                        self.stats
+                            .record(GameRecord::from_game(self.wordle.as_ref(), &variant));
+                        storage::store_stats(&self.stats);
+                    } else {
+                        storage::store_game(self.wordle.as_ref());
                     }
                 }
                 self.guess_text = String::default();
             }
 
+            Message::RequestHint => {
+                let kind = match self.wordle.state().hints.len() % 3 {
+                    0 => HintKind::PresentLetter,
+                    1 => HintKind::RevealPosition,
+                    _ => HintKind::SuggestWord,
+                };
+
+                self.hint_text = Some(match self.wordle.hint(kind) {
+                    Some(hint) => hint.to_string(),
+                    None => "No hint available".to_string(),
+                });
+                storage::store_game(self.wordle.as_ref());
+            }
+
             Message::KeyboardButton(c) => {
                 self.guess_text.push(c);
             }
@@ -254,10 +366,20 @@ impl WordRow {
 }
 
 impl GameVarient {
+    fn from_mutator(mutator: &MutatorConfig) -> GameVarient {
+        match mutator {
+            MutatorConfig::Noop => GameVarient::Jordle,
+            MutatorConfig::Fibble => GameVarient::Fibble,
+            _ => GameVarient::Fuzzle,
+        }
+    }
+
     fn next_varient(&self) -> GameVarient {
         match self {
             GameVarient::Jordle => GameVarient::Fuzzle,
-            GameVarient::Fuzzle => GameVarient::Jordle,
+            GameVarient::Fuzzle => GameVarient::Fibble,
+            GameVarient::Fibble => GameVarient::Absurdle,
+            GameVarient::Absurdle => GameVarient::Jordle,
         }
     }
 }
@@ -267,6 +389,8 @@ impl ToString for GameVarient {
         match self {
             GameVarient::Jordle => "Jordle",
             GameVarient::Fuzzle => "Fuzzle",
+            GameVarient::Fibble => "Fibble",
+            GameVarient::Absurdle => "Absurdle",
         }
         .to_string()
     }
@@ -0,0 +1,45 @@
+use jordle::logic::{Game, SavedGame, Statistics};
+use web_sys::Storage;
+
+const SAVE_KEY: &str = "jordle_save";
+const STATS_KEY: &str = "jordle_stats";
+
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+pub fn store_game(game: &dyn Game) {
+    let storage = match local_storage() {
+        Some(storage) => storage,
+        None => return,
+    };
+
+    if let Some(json) = game.save().and_then(|saved| saved.to_json().ok()) {
+        let _ = storage.set_item(SAVE_KEY, &json);
+    }
+}
+
+pub fn load_game() -> Option<SavedGame> {
+    let json = local_storage()?.get_item(SAVE_KEY).ok()??;
+
+    SavedGame::from_json(&json).ok()
+}
+
+pub fn clear_game() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(SAVE_KEY);
+    }
+}
+
+pub fn load_stats() -> Statistics {
+    local_storage()
+        .and_then(|storage| storage.get_item(STATS_KEY).ok()?)
+        .and_then(|json| Statistics::from_json(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn store_stats(stats: &Statistics) {
+    if let (Some(storage), Ok(json)) = (local_storage(), stats.to_json()) {
+        let _ = storage.set_item(STATS_KEY, &json);
+    }
+}
@@ -1,5 +1,5 @@
 use iced::{button, container, Background, Color};
-use jordle::logic::CharAlignment;
+use jordle::logic::{CharAlignment, LetterStatus};
 
 #[allow(dead_code)]
 pub enum Theme {
@@ -43,6 +43,17 @@ impl From<CharAlignment> for Tile {
     }
 }
 
+impl From<LetterStatus> for Tile {
+    fn from(status: LetterStatus) -> Self {
+        match status {
+            LetterStatus::Unknown => Tile::Pending,
+            LetterStatus::Absent => Tile::NotFound,
+            LetterStatus::Present => Tile::WrongPlace,
+            LetterStatus::Correct => Tile::Correct,
+        }
+    }
+}
+
 impl container::StyleSheet for Tile {
     fn style(&self) -> container::Style {
         let grey_border = Color::from_rgb8(58, 58, 60);
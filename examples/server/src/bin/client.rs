@@ -0,0 +1,59 @@
+use server::{GameId, Request, Response, DEFAULT_ADDR};
+
+use std::env;
+use std::io::{stdin, BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+use anyhow::{bail, Result};
+
+fn main() -> Result<()> {
+    let addr = env::args()
+        .skip_while(|x| x != "--addr")
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+
+    let mut stream = TcpStream::connect(&addr)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut game: Option<GameId> = None;
+
+    println!(
+        "Connected to {}. Commands: new, state, resign, quit, or a word to guess.",
+        addr
+    );
+
+    for line in stdin().lock().lines() {
+        let line = line?;
+        let command = line.trim();
+
+        let request = match (command, game) {
+            ("quit", _) => break,
+            ("new", _) => Request::Create { preset: None },
+            (_, None) => {
+                println!("Start a game with `new` first.");
+                continue;
+            }
+            ("state", Some(game)) => Request::State { game },
+            ("resign", Some(game)) => Request::Resign { game },
+            (word, Some(game)) => Request::Guess {
+                game,
+                word: word.to_string(),
+            },
+        };
+
+        writeln!(stream, "{}", serde_json::to_string(&request)?)?;
+
+        let mut raw = String::new();
+        if reader.read_line(&mut raw)? == 0 {
+            bail!("Server closed the connection");
+        }
+
+        let response: Response = serde_json::from_str(&raw)?;
+        if let Response::Created { game: id, .. } = response {
+            game = Some(id);
+        }
+
+        println!("{}", raw.trim());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use jordle::logic::{GamePreset, GameStatus, WordMatch, WordValidation};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+
+pub type GameId = u64;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    Create {
+        #[serde(default)]
+        preset: Option<GamePreset>,
+    },
+    Guess {
+        game: GameId,
+        word: String,
+    },
+    State {
+        game: GameId,
+    },
+    Resign {
+        game: GameId,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Response {
+    Created {
+        game: GameId,
+        word_size: usize,
+        tries: Option<usize>,
+    },
+    Guess {
+        game: GameId,
+        result: WordValidation,
+        status: GameStatus,
+    },
+    State {
+        game: GameId,
+        status: GameStatus,
+        rows: Vec<WordMatch>,
+        tries: Option<usize>,
+        target: Option<String>,
+    },
+    Resigned {
+        game: GameId,
+        target: String,
+    },
+    Error {
+        message: String,
+    },
+}
@@ -0,0 +1,198 @@
+use jordle::logic::*;
+use server::{GameId, Request, Response, DEFAULT_ADDR};
+
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Instant;
+
+use anyhow::Result;
+
+const FINISHED_GAME_LIMIT: usize = 256;
+const GAME_LIMIT: usize = 1024;
+
+#[derive(Default)]
+struct Sessions {
+    next_id: GameId,
+    games: HashMap<GameId, Wordle>,
+    finished: VecDeque<GameId>,
+    last_active: HashMap<GameId, Instant>,
+}
+
+impl Sessions {
+    fn insert(&mut self, wordle: Wordle) -> GameId {
+        while self.games.len() >= GAME_LIMIT {
+            let idle = self.last_active.iter().min_by_key(|(_, time)| **time);
+            match idle.map(|(game, _)| *game) {
+                Some(game) => self.remove(game),
+                None => break,
+            }
+        }
+
+        self.next_id += 1;
+        let game = self.next_id;
+        self.games.insert(game, wordle);
+        self.last_active.insert(game, Instant::now());
+
+        game
+    }
+
+    fn remove(&mut self, game: GameId) {
+        self.games.remove(&game);
+        self.last_active.remove(&game);
+        self.finished.retain(|x| *x != game);
+    }
+
+    fn track(&mut self, game: GameId, status: GameStatus) {
+        self.last_active.insert(game, Instant::now());
+        if status == GameStatus::Playing || self.finished.contains(&game) {
+            return;
+        }
+
+        self.finished.push_back(game);
+        while self.finished.len() > FINISHED_GAME_LIMIT {
+            if let Some(old) = self.finished.pop_front() {
+                self.games.remove(&old);
+                self.last_active.remove(&old);
+            }
+        }
+    }
+}
+
+type SharedSessions = Arc<Mutex<Sessions>>;
+
+fn main() -> Result<()> {
+    let addr = env::args()
+        .skip_while(|x| x != "--addr")
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+
+    let listener = TcpListener::bind(&addr)?;
+    let sessions = SharedSessions::default();
+    println!("Listening on {}", addr);
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let sessions = sessions.clone();
+
+        thread::spawn(move || {
+            if let Err(err) = handle_client(stream, sessions) {
+                eprintln!("Connection closed: {}", err);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_client(stream: TcpStream, sessions: SharedSessions) -> Result<()> {
+    let mut writer = stream.try_clone()?;
+    let reader = BufReader::new(stream);
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => handle_request(request, &sessions),
+            Err(err) => Response::Error {
+                message: format!("Invalid request: {}", err),
+            },
+        };
+
+        writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+    }
+
+    Ok(())
+}
+
+fn lock(sessions: &SharedSessions) -> MutexGuard<'_, Sessions> {
+    sessions.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn handle_request(request: Request, sessions: &SharedSessions) -> Response {
+    match request {
+        Request::Create { preset } => {
+            let params = match preset.map(|x| GameParameters::try_from_preset(&x)) {
+                Some(Ok(params)) => params,
+                Some(Err(err)) => {
+                    return Response::Error {
+                        message: format!("Invalid preset: {}", err),
+                    }
+                }
+                None => GameParameters::default(),
+            };
+            let wordle = Wordle::new_seeded_game(params);
+            let word_size = wordle.state.target_word.chars().count();
+            let tries = wordle.params.tries;
+
+            let game = lock(sessions).insert(wordle);
+            Response::Created {
+                game,
+                word_size,
+                tries,
+            }
+        }
+        Request::Guess { game, word } => {
+            let mut sessions = lock(sessions);
+            let wordle = match sessions.games.get_mut(&game) {
+                Some(wordle) => wordle,
+                None => return unknown_game(game),
+            };
+
+            let result = wordle.guess(&word);
+            let status = wordle.status();
+            sessions.track(game, status);
+
+            Response::Guess {
+                game,
+                result,
+                status,
+            }
+        }
+        Request::State { game } => {
+            let mut sessions = lock(sessions);
+            let wordle = match sessions.games.get(&game) {
+                Some(wordle) => wordle,
+                None => return unknown_game(game),
+            };
+
+            let status = wordle.status();
+            let response = Response::State {
+                game,
+                status,
+                rows: wordle.state.displayed_guesses.clone(),
+                tries: wordle.params.tries,
+                target: (status != GameStatus::Playing).then(|| wordle.state.target_word.clone()),
+            };
+
+            sessions.track(game, status);
+            response
+        }
+        Request::Resign { game } => {
+            let mut sessions = lock(sessions);
+            let wordle = match sessions.games.get_mut(&game) {
+                Some(wordle) => wordle,
+                None => return unknown_game(game),
+            };
+
+            wordle.resign();
+            let target = wordle.state.target_word.clone();
+            let status = wordle.status();
+            sessions.track(game, status);
+
+            Response::Resigned { game, target }
+        }
+    }
+}
+
+fn unknown_game(game: GameId) -> Response {
+    Response::Error {
+        message: format!("Unknown game {}", game),
+    }
+}
@@ -1,6 +1,6 @@
 use clap::{ArgEnum, Parser, Subcommand};
 use jordle::{
-    logic::{mutator::NoopMutator, params::GameParameters, *},
+    logic::{params::GameParameters, *},
     solver::{
         entropy::{EntropySolver, GlobalFilteredEntropySolver, GlobalShrinkingEntropySolver},
         positional::PositionalSolver,
@@ -8,10 +8,13 @@ use jordle::{
     },
 };
 
+use std::sync::Arc;
+
+use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
 use rand::{prelude::IteratorRandom, Rng};
 
-type WordleSolver<'a> = Box<dyn jordle::solver::solvers::Solver<'a> + 'a>;
+type WordleSolver = Box<dyn jordle::solver::solvers::Solver>;
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -20,6 +23,14 @@ struct Cli {
     command: Commands,
     #[clap(default_value_t = SelectedSolver::Positional, arg_enum)]
     solver: SelectedSolver,
+    #[clap(short, long, default_value_t = 5)]
+    length: usize,
+    #[clap(long)]
+    seed: Option<u64>,
+    #[clap(long)]
+    answers: Option<String>,
+    #[clap(long)]
+    guesses: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -37,8 +48,8 @@ enum SelectedSolver {
     FilteredEntropy,
 }
 
-impl<'a> SelectedSolver {
-    fn create_solver(&self, wordlist: &SolverWordList<'a>) -> Box<dyn Solver<'a> + 'a> {
+impl SelectedSolver {
+    fn create_solver(&self, wordlist: &SolverWordList) -> Box<dyn Solver> {
         match self {
             Self::Positional => Box::new(PositionalSolver::new(wordlist)),
             Self::Entropy => Box::new(EntropySolver::new(wordlist)),
@@ -48,36 +59,39 @@ impl<'a> SelectedSolver {
     }
 }
 
-fn create_simple_game_params() -> GameParameters<'static> {
+#[allow(dead_code)]
+fn create_simple_game_params() -> GameParameters {
     let word_size = (5, 5);
-    let filter_words =
-        |x: &'static str| Some(x).filter(|x| x.len() >= word_size.0 && x.len() <= word_size.1);
 
-    let word_str = include_str!("../../../words/sample.txt");
-    // let word_str = include_str!("../../../words/repeats.txt");
-    // let word_str = include_str!("../../../words/super_simple.txt");
+    let words = WordList::parse(include_str!("../../../words/sample.txt"));
+    // let words = WordList::parse(include_str!("../../../words/repeats.txt"));
+    // let words = WordList::parse(include_str!("../../../words/super_simple.txt"));
 
-    let answer_wordlist: Vec<&str> = word_str.lines().filter_map(filter_words).collect();
+    GameParameters::with_wordlists(&words, &words, word_size)
+}
 
-    let guess_wordlist = answer_wordlist.iter().map(|x| *x).collect();
+fn load_params(command: &Cli) -> Result<GameParameters> {
+    let word_size = (command.length, command.length);
 
-    GameParameters {
-        guess_wordlist,
-        answer_wordlist,
+    let params = GameParameters::from_sources(
+        command.answers.as_deref(),
+        command.guesses.as_deref(),
         word_size,
+    )?;
 
-        mutator: Box::new(NoopMutator {}),
-        tries: Some(6),
-        time_limit: None,
-    }
+    Ok(GameParameters {
+        seed: command.seed,
+        tries: None,
+        ..params
+    })
 }
 
-fn main() {
+fn main() -> Result<()> {
     let command = Cli::parse();
-    let mut rng = rand::thread_rng();
 
     // let params = create_simple_game_params();
-    let params = GameParameters::default();
+    let params = load_params(&command)?;
+    let mut rng = params.rng();
 
     let wordle = Wordle::new_random_game(params, &mut rng);
     let solver = command.solver;
@@ -87,13 +101,15 @@ fn main() {
         Commands::Sample { count } => repeat_auto_game(wordle, count, solver, &mut rng),
         Commands::Target { target } => run_auto_game(wordle, target, solver),
     }
+
+    Ok(())
 }
 
 fn run_auto_game(mut wordle: Wordle, target: String, solver: SelectedSolver) {
     println!("Searching for word: {}", &target);
     wordle.state.target_word = target;
 
-    let game_words: SolverWordList = wordle.params.answer_wordlist.iter().map(|x| *x).collect();
+    let game_words: SolverWordList = wordle.params.answer_wordlist.clone();
 
     let mut solver: WordleSolver = solver.create_solver(&game_words);
 
@@ -113,15 +129,16 @@ fn repeat_auto_game(
     solver: SelectedSolver,
     rng: &mut impl Rng,
 ) {
-    let answer_wordlist: Vec<&str> = wordle.params.answer_wordlist.clone();
+    let answer_wordlist = wordle.params.answer_wordlist.clone();
 
-    let target_words = (0..played_games).map(|_| *answer_wordlist.iter().choose(rng).unwrap());
+    let target_words =
+        (0..played_games).map(|_| answer_wordlist.iter().choose(rng).unwrap().clone());
 
     solve_iter(wordle, solver, target_words, played_games);
 }
 
 fn trial_solver(wordle: Wordle, solver: SelectedSolver) {
-    let target_words: Vec<&str> = wordle.params.answer_wordlist.clone();
+    let target_words = wordle.params.answer_wordlist.clone();
     let target_len = target_words.len();
 
     solve_iter(wordle, solver, target_words.into_iter(), target_len);
@@ -137,10 +154,10 @@ fn progress_bar(count: u64) -> ProgressBar {
     bar
 }
 
-fn solve_iter<'a>(
+fn solve_iter(
     mut wordle: Wordle,
     solver: SelectedSolver,
-    target_words: impl Iterator<Item = &'a str>,
+    target_words: impl Iterator<Item = Arc<str>>,
     target_count: usize,
 ) {
     let mut total_guess_count = 0;
@@ -201,10 +218,12 @@ fn auto_game(wordle: &mut Wordle, solver: &mut WordleSolver) -> Vec<String> {
 }
 
 fn take_guess(wordle: &mut Wordle, solver: &mut WordleSolver) -> (bool, String) {
-    let guess_word = solver.guess().expect(&format!(
-        "Failed to find guess with word {}",
-        &wordle.state.target_word
-    ));
+    let guess_word = solver.guess().unwrap_or_else(|| {
+        panic!(
+            "Failed to find guess with word {}",
+            &wordle.state.target_word
+        )
+    });
 
     let guess_result = wordle.guess(&guess_word);
 
@@ -216,8 +235,9 @@ fn take_guess(wordle: &mut Wordle, solver: &mut WordleSolver) -> (bool, String)
 
             solver.narrow_words(&matches);
 
-            return (true, guess_word);
+            (true, guess_word)
         }
+        WordValidation::GameOver(_) => (false, guess_word),
         WordValidation::Invalid(_, _) => {
             eprintln!("Guessed an invalid word: {}", &guess_word);
             unreachable!()
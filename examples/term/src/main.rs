@@ -1,41 +1,281 @@
+use jordle::logic::mutator::MutatorConfig;
 use jordle::logic::*;
 
+use std::env;
+use std::fs;
 use std::io::stdin;
+use std::path::Path;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use owo_colors::colors::*;
 use owo_colors::OwoColorize;
+use rand::Rng;
+
+const SAVE_PATH: &str = "jordle_save.json";
+const STATS_PATH: &str = "jordle_stats.json";
+
+type WordSources = (Option<String>, Option<String>);
+
+struct GameInfo {
+    variant: String,
+    sources: WordSources,
+    game_number: Option<usize>,
+    seeded: bool,
+}
+
+impl GameInfo {
+    fn new(variant: &str, sources: &WordSources) -> Self {
+        Self {
+            variant: variant.to_string(),
+            sources: sources.clone(),
+            game_number: None,
+            seeded: false,
+        }
+    }
+}
 
 fn main() -> Result<()> {
     let mut rng = rand::thread_rng();
 
-    let params = GameParameters::default();
+    if Path::new(SAVE_PATH).exists() {
+        let saved = SavedGame::from_json(&fs::read_to_string(SAVE_PATH)?)?;
+        let sources = (saved.answers.clone(), saved.guesses.clone());
+        let params = GameParameters::from_sources(
+            sources.0.as_deref(),
+            sources.1.as_deref(),
+            saved.preset.word_size,
+        )?
+        .with_preset(&saved.preset);
+        let mut wordle = Wordle::resume_with_params(&saved, params);
+        fs::remove_file(SAVE_PATH)?;
+
+        println!("Resuming saved game.");
+        let variant = saved.variant.as_deref().unwrap_or("Jordle");
+        let info = GameInfo {
+            game_number: saved.game_number,
+            seeded: matches!(variant, "Jordle" | "Fibble"),
+            ..GameInfo::new(variant, &sources)
+        };
+        return play_regular_game(&mut wordle, &info);
+    }
+
+    let sources = (arg_value("--answers"), arg_value("--guesses"));
+
+    let word_size = match arg_value("--length") {
+        Some(length) => {
+            let length = length.parse()?;
+            (length, length)
+        }
+        None => (5, 5),
+    };
+
+    let seed = match arg_value("--seed") {
+        Some(seed) => seed.parse()?,
+        None => rng.gen(),
+    };
+
+    let mutator = if env::args().any(|x| x == "--fibble") {
+        MutatorConfig::Fibble
+    } else {
+        MutatorConfig::Noop
+    };
+
+    let params = GameParameters {
+        hard_mode: env::args().any(|x| x == "--hard"),
+        undo: if env::args().any(|x| x == "--practice") {
+            UndoPolicy::practice()
+        } else {
+            UndoPolicy::default()
+        },
+        time_limit: arg_secs("--time-limit")?,
+        guess_time_limit: arg_secs("--guess-time")?,
+        mutator: mutator.build(),
+        seed: Some(seed),
+        ..GameParameters::from_sources(sources.0.as_deref(), sources.1.as_deref(), word_size)?
+    };
+
+    if env::args().any(|x| x == "--daily") {
+        let date = match arg_value("--date") {
+            Some(date) => date.parse()?,
+            None => PuzzleDate::today(),
+        };
+
+        let mut wordle = Wordle::new_daily_game(params, date, None)
+            .ok_or_else(|| anyhow!("No daily puzzle for {}", date))?;
+
+        println!(
+            "Daily puzzle #{} ({})",
+            date.puzzle_number().unwrap_or_default(),
+            date
+        );
+        let info = GameInfo {
+            game_number: date.puzzle_number(),
+            ..GameInfo::new("Daily", &sources)
+        };
+        return play_regular_game(&mut wordle, &info);
+    }
+
+    if let Some(word) = arg_value("--create-challenge") {
+        let challenge = Challenge {
+            tries: params.tries,
+            hard_mode: params.hard_mode,
+            mutator: params.mutator.config(),
+            ..Challenge::new(&word)
+        };
+        let code = challenge.encode();
+        let challenge = Challenge::decode(&code)?;
+        let params = GameParameters::from_sources(
+            sources.0.as_deref(),
+            sources.1.as_deref(),
+            challenge.word_size(),
+        )?;
+        challenge.into_wordle(params)?;
+
+        println!("Challenge code: {}", code);
+        return Ok(());
+    }
+
+    if let Some(code) = arg_value("--challenge") {
+        let challenge = Challenge::decode(&code)?;
+        let params = GameParameters::from_sources(
+            sources.0.as_deref(),
+            sources.1.as_deref(),
+            challenge.word_size(),
+        )?;
+        let mut wordle = challenge.into_wordle(params)?;
+        return play_regular_game(&mut wordle, &GameInfo::new("Challenge", &sources));
+    }
+
+    if let Some(players) = arg_value("--players") {
+        let names = (1..=players.parse()?)
+            .map(|x| format!("Player {}", x))
+            .collect();
+        let mode = if env::args().any(|x| x == "--relay") {
+            RaceMode::Relay
+        } else {
+            RaceMode::Turns
+        };
+
+        let mut rng = params.rng();
+        let mut race = Race::new_random_game(params, names, mode, &mut rng).ok_or_else(|| {
+            anyhow!(
+                "Races need between {} and {} players",
+                race::MIN_PLAYERS,
+                race::MAX_PLAYERS
+            )
+        })?;
+        return play_race(&mut race);
+    }
+
+    if env::args().any(|x| x == "--absurdle") {
+        let mut absurdle = Absurdle::new_game(params);
+        return play_regular_game(&mut absurdle, &GameInfo::new("Absurdle", &sources));
+    }
+
+    let variant = match params.mutator.config() {
+        MutatorConfig::Fibble => "Fibble",
+        _ => "Jordle",
+    };
+
+    let mut wordle = Wordle::new_seeded_game(params);
+    let info = GameInfo {
+        seeded: true,
+        ..GameInfo::new(variant, &sources)
+    };
+    play_regular_game(&mut wordle, &info)
+}
 
-    let wordle = Wordle::new_random_game(params, &mut rng);
-    play_regular_game(wordle)
+fn absolute_source(source: &Option<String>) -> Result<Option<String>> {
+    source
+        .as_deref()
+        .map(|x| Ok(fs::canonicalize(x)?.to_string_lossy().into_owned()))
+        .transpose()
 }
 
-fn play_regular_game(mut wordle: Wordle) -> Result<()> {
-    let params = &wordle.params;
-    let time_limit = params
-        .time_limit
-        .clone()
-        .unwrap_or(Duration::from_secs(60 * 60));
-    let attempt_limit = params.tries.unwrap_or(100);
-    let start_time = wordle.state.start_time;
+fn play_regular_game(wordle: &mut dyn Game, info: &GameInfo) -> Result<()> {
+    let (variant, sources) = (info.variant.as_str(), &info.sources);
+
+    let attempt_limit = wordle.params().tries.unwrap_or(100);
 
     println!(
-        "You have {} tries and {} seconds to guess a {} letter word!",
+        "You have {} tries to guess a {} letter word!",
         attempt_limit,
-        time_limit.as_secs(),
-        params.word_size.0
+        wordle.state().target_word.chars().count()
     );
 
-    let mut attempt_number = 0;
+    if let Some(remaining) = wordle.state().remaining_time(wordle.params()) {
+        println!(
+            "The clock is running: {} seconds left.",
+            remaining.as_secs()
+        );
+    }
+
+    for matches in wordle.state().displayed_guesses.iter() {
+        matches.char_matches().for_each(print_char);
+        println!();
+    }
 
-    while attempt_number < attempt_limit {
+    while wordle.status() == GameStatus::Playing {
         let guessed_word = get_user_guess()?;
+
+        if guessed_word == ":quit" {
+            let stdin_lists = [&sources.0, &sources.1]
+                .iter()
+                .any(|x| x.as_deref() == Some("-"));
+
+            match wordle.save() {
+                Some(_) if stdin_lists => {
+                    println!("Games using word lists from stdin can't be saved, game abandoned.")
+                }
+                Some(saved) => {
+                    let saved = SavedGame {
+                        variant: Some(variant.to_string()),
+                        game_number: info.game_number,
+                        answers: absolute_source(&sources.0)?,
+                        guesses: absolute_source(&sources.1)?,
+                        ..saved
+                    };
+                    fs::write(SAVE_PATH, saved.to_json()?)?;
+                    println!("Game saved, run again to resume.");
+                }
+                None => println!("{} games can't be saved, game abandoned.", variant),
+            }
+            return Ok(());
+        }
+
+        if let Some(kind) = guessed_word.strip_prefix('?') {
+            let kind = match kind {
+                "pos" => HintKind::RevealPosition,
+                "word" => HintKind::SuggestWord,
+                _ => HintKind::PresentLetter,
+            };
+
+            match wordle.hint(kind) {
+                Some(hint) => println!("Hint: {}", hint),
+                None => println!("No hint available."),
+            }
+            continue;
+        }
+
+        if let Some(word) = guessed_word.strip_prefix(":why ") {
+            print_contradictions(wordle, word.trim());
+            continue;
+        }
+
+        if guessed_word == ":undo" {
+            if wordle.undo() {
+                println!("Undid last guess.");
+                for matches in wordle.state().displayed_guesses.iter() {
+                    matches.char_matches().for_each(print_char);
+                    println!();
+                }
+            } else {
+                println!("Nothing to undo.");
+            }
+            continue;
+        }
+
         let guess_result = wordle.guess(guessed_word.as_str());
 
         match guess_result {
@@ -44,59 +284,205 @@ fn play_regular_game(mut wordle: Wordle) -> Result<()> {
 
                 print!(" - ");
 
-                match guess {
-                    GuessResult::Correct => {
-                        println!("You guessed it!");
-                        break;
-                    }
-                    GuessResult::Wrong => attempt_number += 1,
+                if let GuessResult::Correct = guess {
+                    println!("You guessed it!");
+                    break;
                 }
             }
-            WordValidation::Invalid(reason, _) => match reason {
-                InvalidationReason::WrongLength => {
-                    println!("Invalid length, try again.");
-                }
-                InvalidationReason::UnknownWord => {
-                    println!("Invalid word, try again.");
-                }
-                InvalidationReason::RepeatWord => {
-                    println!("Repeated guess, try again.");
-                }
-            },
+            WordValidation::GameOver(_) => break,
+            WordValidation::Invalid(InvalidationReason::HardMode(violation), word) => {
+                print_invalid(InvalidationReason::HardMode(violation));
+                print_contradictions(wordle, &word);
+            }
+            WordValidation::Invalid(reason, _) => print_invalid(reason),
         }
 
-        let current_dur = start_time.map(|x| x.elapsed()).unwrap_or(Duration::ZERO);
-
-        if current_dur >= time_limit {
-            println!("Out of time :(");
+        if wordle.status() != GameStatus::Playing {
+            println!();
             break;
         }
-        let diff_dur = time_limit - current_dur;
 
-        println!(
-            "You have {} tries and {} seconds left before your final guess.",
-            attempt_limit - attempt_number,
-            diff_dur.as_secs()
-        )
+        let attempt_number = wordle.state().prev_guesses.len();
+        match wordle.state().remaining_time(wordle.params()) {
+            Some(remaining) => println!(
+                "You have {} tries and {} seconds left before your final guess.",
+                attempt_limit - attempt_number,
+                remaining.as_secs()
+            ),
+            None => println!("You have {} tries left.", attempt_limit - attempt_number),
+        }
+
+        print_keyboard(&wordle.state().keyboard);
+    }
+
+    match wordle.status() {
+        GameStatus::Lost(LossReason::NoTriesLeft) => println!("Sorry you ran out of guesses."),
+        GameStatus::Lost(LossReason::Timeout) => println!("Out of time :("),
+        _ => {}
+    }
+
+    println!("The word was: {}", &wordle.state().target_word);
+
+    let palette = if env::args().any(|x| x == "--high-contrast") {
+        Palette::HighContrast
+    } else {
+        Palette::Standard
+    };
+
+    let share_options = ShareOptions {
+        palette,
+        game_number: info.game_number,
+        ..ShareOptions::default()
+    };
+
+    println!("{}", ScoringConfig::default().score(wordle));
+
+    if let Some(lies) = wordle.reveal_lies().filter(|x| !x.is_empty()) {
+        println!("\nYou were lied to {} times:", lies.len());
+        for lie in lies {
+            println!("  {}", lie);
+        }
+    }
+
+    if let Some(grid) = wordle.share(&share_options) {
+        println!("\n{}\n", grid);
     }
 
-    if attempt_number >= attempt_limit {
-        println!("Sorry you ran out of guesses.");
+    if let Some(seed) = wordle.params().seed.filter(|_| info.seeded) {
+        println!("Game seed: {}", seed);
     }
 
-    println!("The word was: {}", &wordle.state.target_word);
+    let mut stats = Statistics::load(STATS_PATH)?;
+    stats.record(GameRecord::from_game(wordle, variant));
+    stats.save(STATS_PATH)?;
+
+    println!("\n{}", stats);
 
     Ok(())
 }
 
+fn play_race(race: &mut Race) -> Result<()> {
+    println!(
+        "{} players racing to guess the same {} letter word!",
+        race.players.len(),
+        race.players[0].state.target_word.chars().count()
+    );
+
+    while !race.is_over() {
+        let player = race.current_player();
+        println!("\n{}'s turn:", player.name);
+        for matches in player.state.displayed_guesses.iter() {
+            matches.char_matches().for_each(print_char);
+            println!();
+        }
+
+        let guessed_word = get_user_guess()?;
+        match race.guess(guessed_word.as_str()) {
+            WordValidation::Valid(guess, matches) => {
+                matches.char_matches().for_each(print_char);
+                if let GuessResult::Correct = guess {
+                    print!(" - You guessed it!");
+                }
+                println!();
+            }
+            WordValidation::GameOver(_) => {}
+            WordValidation::Invalid(reason, _) => print_invalid(reason),
+        }
+    }
+
+    println!("\nThe word was: {}", race.players[0].state.target_word);
+    for (place, result) in race.results().iter().enumerate() {
+        let outcome = match result.status {
+            GameStatus::Won => format!("{} guesses", result.guesses),
+            _ => "did not finish".to_string(),
+        };
+
+        println!(
+            "{}. {} - {} in {:.1}s",
+            place + 1,
+            result.name,
+            outcome,
+            result.time.as_secs_f32()
+        );
+    }
+
+    let winners: Vec<String> = race.winners().into_iter().map(|x| x.name).collect();
+    match winners.len() {
+        0 => println!("Nobody guessed it."),
+        1 => println!("{} wins!", winners[0]),
+        _ => println!("It's a tie between {}!", winners.join(" and ")),
+    }
+
+    Ok(())
+}
+
+fn print_invalid(reason: InvalidationReason) {
+    match reason {
+        InvalidationReason::WrongLength => {
+            println!("Invalid length, try again.");
+        }
+        InvalidationReason::UnknownWord => {
+            println!("Invalid word, try again.");
+        }
+        InvalidationReason::RepeatWord => {
+            println!("Repeated guess, try again.");
+        }
+        InvalidationReason::HardMode(violation) => {
+            println!("Hard mode: {}, try again.", violation);
+        }
+        InvalidationReason::Paused => {
+            println!("The game is paused.");
+        }
+    }
+}
+
+fn arg_secs(name: &str) -> Result<Option<Duration>> {
+    match arg_value(name) {
+        Some(secs) => Ok(Some(Duration::from_secs(secs.parse()?))),
+        None => Ok(None),
+    }
+}
+
+fn arg_value(name: &str) -> Option<String> {
+    env::args().skip_while(|x| x != name).nth(1)
+}
+
 fn get_user_guess() -> Result<String> {
     let mut raw_input = String::new();
-    stdin().read_line(&mut raw_input)?;
+    if stdin().read_line(&mut raw_input)? == 0 {
+        bail!("No more input");
+    }
     let guessed_word = raw_input.trim().to_string();
 
     Ok(guessed_word)
 }
 
+fn print_contradictions(wordle: &dyn Game, word: &str) {
+    let contradictions = wordle.contradictions(word);
+
+    if contradictions.is_empty() {
+        println!("{} fits every guess so far.", word.to_uppercase());
+    }
+
+    for contradiction in contradictions {
+        println!("  {}", contradiction);
+    }
+}
+
+fn print_keyboard(keyboard: &KeyboardState) {
+    for row in ["qwertyuiop", " asdfghjkl", "  zxcvbnm"] {
+        for c in row.chars() {
+            match keyboard.status(c) {
+                LetterStatus::Correct => print!("{}", c.fg::<Black>().bg::<Green>()),
+                LetterStatus::Present => print!("{}", c.fg::<Black>().bg::<BrightBlue>()),
+                LetterStatus::Absent => print!("{}", c.fg::<White>().bg::<BrightBlack>()),
+                LetterStatus::Unknown => print!("{}", c),
+            }
+        }
+        println!();
+    }
+}
+
 fn print_char(cmatch: CharMatch) {
     let (c, calign) = (cmatch.c, &cmatch.align);
     match *calign {
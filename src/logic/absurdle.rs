@@ -0,0 +1,135 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::game::Game;
+use super::language::letter_count;
+use super::mutator::RowContext;
+use super::params::GameParameters;
+use super::state::{GameState, GameStatus};
+use super::types::{CharAlignment, WordMatch};
+use super::wordle::{
+    match_word_in, validate_guess, GuessResult, InvalidationReason, WordValidation,
+};
+
+pub struct Absurdle {
+    pub params: GameParameters,
+    pub state: GameState,
+    pub candidates: Vec<Arc<str>>,
+}
+
+impl Absurdle {
+    pub fn new_game(mut params: GameParameters) -> Self {
+        params.reseed_mutator();
+        let candidates = params.answer_wordlist.clone();
+        let target = candidates.first().unwrap().to_string();
+
+        Self {
+            state: GameState::new_game(&params, target),
+            candidates,
+            params,
+        }
+    }
+
+    pub fn restart(self) -> Self {
+        let params = self.params;
+
+        Self::new_game(params)
+    }
+
+    pub fn guess(&mut self, guessed_word: &str) -> WordValidation {
+        if let status @ (GameStatus::Won | GameStatus::Lost(_)) =
+            self.state.update_status(&self.params)
+        {
+            return WordValidation::GameOver(status);
+        }
+
+        let canonical = self.params.find_word(guessed_word);
+        let guessed_word = canonical.as_deref().unwrap_or(guessed_word);
+        if let Some(reason) =
+            validate_guess(&self.params, &self.state.displayed_guesses, guessed_word)
+        {
+            return WordValidation::Invalid(reason, guessed_word.to_string());
+        }
+
+        let guess_len = letter_count(guessed_word);
+        let language = self.params.language;
+        let mut buckets: HashMap<Vec<CharAlignment>, Vec<Arc<str>>> = HashMap::new();
+        for candidate in self
+            .candidates
+            .iter()
+            .filter(|x| letter_count(x) == guess_len)
+        {
+            let aligns = match_word_in(language, candidate, guessed_word).aligns;
+            buckets.entry(aligns).or_default().push(candidate.clone());
+        }
+
+        let (aligns, candidates) = match buckets.into_iter().max_by_key(|(aligns, words)| {
+            let solved = aligns.iter().all(|x| matches!(x, CharAlignment::Exact));
+            (
+                words.len(),
+                !solved,
+                Reverse(score_alignment(aligns)),
+                Reverse(words[0].clone()),
+            )
+        }) {
+            Some(bucket) => bucket,
+            None => {
+                return WordValidation::Invalid(
+                    InvalidationReason::WrongLength,
+                    guessed_word.to_string(),
+                )
+            }
+        };
+
+        self.state.target_word = candidates[0].to_string();
+        self.candidates = candidates;
+
+        let matches = WordMatch {
+            word: guessed_word.to_string(),
+            aligns,
+        };
+
+        if self.state.target_word == *guessed_word {
+            self.state.push_guess(matches.clone(), matches.clone());
+            self.state.update_status(&self.params);
+            return WordValidation::Valid(GuessResult::Correct, matches);
+        }
+
+        let displayed = self.params.mutator.mutate(&RowContext {
+            guess: guessed_word,
+            target: &self.state.target_word,
+            matches: &matches,
+            prev_rows: &self.state.prev_guesses,
+        });
+        self.state.push_guess(matches, displayed.clone());
+        self.state.update_status(&self.params);
+
+        WordValidation::Valid(GuessResult::Wrong, displayed)
+    }
+}
+
+impl Game for Absurdle {
+    fn params(&self) -> &GameParameters {
+        &self.params
+    }
+
+    fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    fn guess(&mut self, guessed_word: &str) -> WordValidation {
+        Absurdle::guess(self, guessed_word)
+    }
+}
+
+fn score_alignment(aligns: &[CharAlignment]) -> usize {
+    aligns
+        .iter()
+        .map(|x| match x {
+            CharAlignment::Exact => 2,
+            CharAlignment::Misplaced => 1,
+            CharAlignment::NotFound => 0,
+        })
+        .sum()
+}
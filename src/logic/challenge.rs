@@ -0,0 +1,229 @@
+use anyhow::{anyhow, bail, ensure, Result};
+
+use super::hash::{fnv1a, splitmix64};
+use super::language::{letter_count, Language};
+use super::mutator::MutatorConfig;
+use super::params::GameParameters;
+use super::wordle::Wordle;
+
+const CHALLENGE_VERSION: u8 = 1;
+const CHALLENGE_KEY: u64 = 0x6a6f72646c65;
+const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Challenge {
+    pub target: String,
+    pub tries: Option<usize>,
+    pub hard_mode: bool,
+    pub mutator: MutatorConfig,
+    pub language: Language,
+}
+
+impl Challenge {
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+            tries: Some(6),
+            hard_mode: false,
+            mutator: MutatorConfig::Noop,
+            language: Language::English,
+        }
+    }
+
+    pub fn from_wordle(wordle: &Wordle) -> Self {
+        Self {
+            target: wordle.state.target_word.clone(),
+            tries: wordle.params.tries,
+            hard_mode: wordle.params.hard_mode,
+            mutator: wordle.params.mutator.config(),
+            language: wordle.params.language,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let mut payload = vec![
+            CHALLENGE_VERSION,
+            self.hard_mode as u8,
+            self.tries.unwrap_or(0).min(u8::MAX as usize) as u8,
+            language_id(self.language),
+        ];
+
+        match self.mutator {
+            MutatorConfig::Noop => payload.push(0),
+            MutatorConfig::Fibble => payload.push(1),
+            MutatorConfig::StepProb {
+                exact,
+                misplaced,
+                not_found,
+            } => payload.extend([2, percent(exact), percent(misplaced), percent(not_found)]),
+        }
+        payload.extend(self.target.as_bytes());
+
+        let checksum = (fnv1a(&payload) as u32).to_be_bytes();
+        let mut bytes = checksum.to_vec();
+        bytes.extend(scramble(&payload, checksum));
+
+        to_base32(&bytes)
+    }
+
+    pub fn decode(code: &str) -> Result<Self> {
+        let bytes = from_base32(code.trim())?;
+        ensure!(bytes.len() > 4, "Challenge code is too short");
+
+        let (checksum, data) = bytes.split_at(4);
+        let checksum = [checksum[0], checksum[1], checksum[2], checksum[3]];
+        let payload = scramble(data, checksum);
+        ensure!(
+            (fnv1a(&payload) as u32).to_be_bytes() == checksum,
+            "Challenge code has been tampered with"
+        );
+
+        let mut bytes = payload.into_iter();
+        let mut next = || {
+            bytes
+                .next()
+                .ok_or_else(|| anyhow!("Challenge code is truncated"))
+        };
+
+        let version = next()?;
+        if version != CHALLENGE_VERSION {
+            bail!("Unsupported challenge version {}", version);
+        }
+
+        let hard_mode = next()? != 0;
+        let tries = Some(next()? as usize).filter(|x| *x > 0);
+        let language = language_from_id(next()?)?;
+        let mutator = match next()? {
+            0 => MutatorConfig::Noop,
+            1 => MutatorConfig::Fibble,
+            2 => MutatorConfig::StepProb {
+                exact: next()? as f64 / 100.0,
+                misplaced: next()? as f64 / 100.0,
+                not_found: next()? as f64 / 100.0,
+            },
+            tag => bail!("Unknown mutator {} in challenge code", tag),
+        };
+
+        let target = String::from_utf8(bytes.collect())?;
+
+        Ok(Self {
+            target,
+            tries,
+            hard_mode,
+            mutator,
+            language,
+        })
+    }
+}
+
+impl Challenge {
+    pub fn word_size(&self) -> (usize, usize) {
+        let length = letter_count(&self.target);
+        (length, length)
+    }
+
+    pub fn into_wordle(self, params: GameParameters) -> Result<Wordle> {
+        let params = GameParameters {
+            tries: self.tries,
+            hard_mode: self.hard_mode,
+            mutator: self.mutator.build(),
+            language: self.language,
+            ..params
+        };
+
+        let target = params
+            .find_word(&self.target)
+            .ok_or_else(|| anyhow!("Challenge word is not in the word list"))?;
+
+        Ok(Wordle::new_game(params, target.to_string()))
+    }
+}
+
+impl Wordle {
+    pub fn from_challenge(code: &str) -> Result<Self> {
+        let challenge = Challenge::decode(code)?;
+        ensure!(
+            challenge.language == Language::English,
+            "Challenge needs a {:?} word list",
+            challenge.language
+        );
+
+        let params = GameParameters::with_word_size(challenge.word_size());
+        challenge.into_wordle(params)
+    }
+}
+
+fn percent(prob: f64) -> u8 {
+    (prob.clamp(0.0, 1.0) * 100.0).round() as u8
+}
+
+fn language_id(language: Language) -> u8 {
+    match language {
+        Language::English => 0,
+        Language::Spanish => 1,
+        Language::German => 2,
+        Language::French => 3,
+    }
+}
+
+fn language_from_id(id: u8) -> Result<Language> {
+    match id {
+        0 => Ok(Language::English),
+        1 => Ok(Language::Spanish),
+        2 => Ok(Language::German),
+        3 => Ok(Language::French),
+        _ => bail!("Unknown language {} in challenge code", id),
+    }
+}
+
+fn scramble(bytes: &[u8], checksum: [u8; 4]) -> Vec<u8> {
+    let mut state = CHALLENGE_KEY ^ u32::from_be_bytes(checksum) as u64;
+
+    bytes
+        .iter()
+        .map(|x| x ^ splitmix64(&mut state) as u8)
+        .collect()
+}
+
+fn to_base32(bytes: &[u8]) -> String {
+    let mut code = String::new();
+    let (mut buffer, mut bits) = (0_u32, 0);
+
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            code.push(ALPHABET[(buffer >> bits) as usize & 31] as char);
+        }
+    }
+
+    if bits > 0 {
+        code.push(ALPHABET[(buffer << (5 - bits)) as usize & 31] as char);
+    }
+
+    code
+}
+
+fn from_base32(code: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits) = (0_u32, 0);
+
+    for c in code.chars() {
+        let value = ALPHABET
+            .iter()
+            .position(|x| *x as char == c.to_ascii_lowercase())
+            .ok_or_else(|| anyhow!("Invalid character in challenge code"))?;
+
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,170 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail};
+
+use super::hash::{fnv1a, splitmix64};
+use super::params::GameParameters;
+use super::wordle::Wordle;
+
+pub const DAILY_EPOCH: PuzzleDate = PuzzleDate {
+    year: 2021,
+    month: 6,
+    day: 19,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl PuzzleDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let date = Self { year, month, day };
+
+        Some(date).filter(|x| Self::from_days(x.to_days()) == *x)
+    }
+
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_secs())
+            .unwrap_or(0);
+
+        Self::from_days((secs / 86400) as i64)
+    }
+
+    pub fn puzzle_number(&self) -> Option<usize> {
+        let days = self.to_days() - DAILY_EPOCH.to_days();
+
+        usize::try_from(days).ok()
+    }
+
+    pub fn from_puzzle_number(number: usize) -> Self {
+        Self::from_days(DAILY_EPOCH.to_days() + number as i64)
+    }
+
+    fn to_days(self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = (year_of_era + era * 400) as i32 + (month <= 2) as i32;
+
+        Self { year, month, day }
+    }
+}
+
+impl Display for PuzzleDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for PuzzleDate {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('-').collect();
+        if parts.len() != 3 {
+            bail!("Expected a date formatted as YYYY-MM-DD, got {}", s);
+        }
+
+        PuzzleDate::new(parts[0].parse()?, parts[1].parse()?, parts[2].parse()?)
+            .ok_or_else(|| anyhow!("Invalid calendar date {}", s))
+    }
+}
+
+pub fn daily_index(puzzle_number: usize, list_len: usize, salt: Option<&str>) -> usize {
+    let cycle = puzzle_number / list_len;
+    let position = puzzle_number % list_len;
+
+    let mut seed = fnv1a(salt.unwrap_or("").as_bytes()) ^ (cycle as u64);
+    let mut order: Vec<usize> = (0..list_len).collect();
+    for i in (1..list_len).rev() {
+        let j = (splitmix64(&mut seed) % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+
+    order[position]
+}
+
+impl Wordle {
+    pub fn new_daily_game(
+        params: GameParameters,
+        date: PuzzleDate,
+        salt: Option<&str>,
+    ) -> Option<Self> {
+        let puzzle_number = date.puzzle_number()?;
+        let list_len = params.answer_wordlist.len();
+        if list_len == 0 {
+            return None;
+        }
+
+        let index = daily_index(puzzle_number, list_len, salt);
+        let target = params.answer_wordlist[index].to_string();
+
+        Some(Self::new_game(params, target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_words_are_pinned() {
+        let cases = [
+            ("2021-06-19", None, "dwell"),
+            ("2024-03-01", None, "gusto"),
+            ("2024-03-01", Some("jordle"), "inlay"),
+            ("2026-10-18", None, "gonad"),
+            ("2028-01-14", None, "idiom"),
+        ];
+
+        for (date, salt, word) in cases {
+            let date = date.parse().unwrap();
+            let wordle = Wordle::new_daily_game(GameParameters::default(), date, salt).unwrap();
+
+            assert_eq!(wordle.state.target_word, word, "{} {:?}", date, salt);
+        }
+    }
+
+    #[test]
+    fn each_word_appears_once_per_cycle() {
+        let list_len = 97;
+
+        for cycle in 0..4 {
+            let mut seen: Vec<usize> = (0..list_len)
+                .map(|x| daily_index(cycle * list_len + x, list_len, Some("salt")))
+                .collect();
+            seen.sort_unstable();
+
+            assert_eq!(seen, (0..list_len).collect::<Vec<_>>());
+        }
+    }
+}
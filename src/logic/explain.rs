@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::knowledge::Knowledge;
+use super::language::Language;
+use super::types::WordMatch;
+use super::wordle::match_word_in;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContradictionKind {
+    WrongLength { expected: usize },
+    MissingExact { pos: usize, c: char },
+    ForbiddenPosition { pos: usize, c: char },
+    MissingLetter { c: char, count: usize },
+    AbsentLetter(char),
+    ExcessLetter { c: char, count: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contradiction {
+    pub row: usize,
+    pub kind: ContradictionKind,
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let row = self.row + 1;
+
+        match self.kind {
+            ContradictionKind::WrongLength { expected } => {
+                write!(f, "guess {} has {} letters", row, expected)
+            }
+            ContradictionKind::MissingExact { pos, c } => write!(
+                f,
+                "guess {} showed {} is in position {}",
+                row,
+                c.to_uppercase(),
+                pos + 1
+            ),
+            ContradictionKind::ForbiddenPosition { pos, c } => write!(
+                f,
+                "guess {} showed {} is not in position {}",
+                row,
+                c.to_uppercase(),
+                pos + 1
+            ),
+            ContradictionKind::MissingLetter { c, count: 1 } => {
+                write!(f, "guess {} requires {}", row, c.to_uppercase())
+            }
+            ContradictionKind::MissingLetter { c, count } => write!(
+                f,
+                "guess {} requires {} {}s",
+                row,
+                count_name(count),
+                c.to_uppercase()
+            ),
+            ContradictionKind::AbsentLetter(c) => write!(
+                f,
+                "guess {} showed {} is not in the word",
+                row,
+                c.to_uppercase()
+            ),
+            ContradictionKind::ExcessLetter { c, count: 1 } => write!(
+                f,
+                "guess {} showed there is only one {}",
+                row,
+                c.to_uppercase()
+            ),
+            ContradictionKind::ExcessLetter { c, count } => write!(
+                f,
+                "guess {} showed there are only {} {}s",
+                row,
+                count_name(count),
+                c.to_uppercase()
+            ),
+        }
+    }
+}
+
+pub fn explain(language: Language, rows: &[WordMatch], word: &str) -> Vec<Contradiction> {
+    rows.iter()
+        .enumerate()
+        .flat_map(|(row, matches)| {
+            explain_row(language, matches, word)
+                .into_iter()
+                .map(move |kind| Contradiction { row, kind })
+        })
+        .collect()
+}
+
+fn explain_row(language: Language, matches: &WordMatch, word: &str) -> Vec<ContradictionKind> {
+    let chars: Vec<char> = word.chars().map(|c| language.fold_char(c)).collect();
+
+    if chars.len() != matches.aligns.len() {
+        return vec![ContradictionKind::WrongLength {
+            expected: matches.aligns.len(),
+        }];
+    }
+
+    if match_word_in(language, word, &matches.word).aligns == matches.aligns {
+        return vec![];
+    }
+
+    let knowledge = Knowledge::from_rows_in(language, [matches]);
+    let mut found = Vec::new();
+
+    for (pos, (fixed, c)) in knowledge.fixed.iter().zip(chars.iter()).enumerate() {
+        for x in fixed.iter().filter(|x| *x != c) {
+            found.push(ContradictionKind::MissingExact { pos, c: *x });
+        }
+    }
+
+    for (pos, (excluded, c)) in knowledge.excluded.iter().zip(chars.iter()).enumerate() {
+        if excluded.contains(c) && !knowledge.is_absent(*c) {
+            found.push(ContradictionKind::ForbiddenPosition { pos, c: *c });
+        }
+    }
+
+    let mut letters: Vec<char> = language.fold(&matches.word).chars().collect();
+    letters.sort_unstable();
+    letters.dedup();
+
+    for c in letters {
+        let count = chars.iter().filter(|x| **x == c).count();
+        let min = *knowledge.min.get(&c).unwrap_or(&0);
+
+        if knowledge.is_absent(c) {
+            if count > 0 {
+                found.push(ContradictionKind::AbsentLetter(c));
+            }
+        } else if count < min {
+            found.push(ContradictionKind::MissingLetter { c, count: min });
+        } else if let Some(max) = knowledge.max.get(&c).filter(|max| count > **max) {
+            found.push(ContradictionKind::ExcessLetter { c, count: *max });
+        }
+    }
+
+    found
+}
+
+fn count_name(count: usize) -> String {
+    match count {
+        2 => "two".to_string(),
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        5 => "five".to_string(),
+        _ => count.to_string(),
+    }
+}
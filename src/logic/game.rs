@@ -0,0 +1,45 @@
+use super::explain::{explain, Contradiction};
+use super::hint::{Hint, HintKind};
+use super::params::GameParameters;
+use super::save::SavedGame;
+use super::share::ShareOptions;
+use super::state::{GameState, GameStatus, Lie};
+use super::wordle::WordValidation;
+
+pub trait Game {
+    fn params(&self) -> &GameParameters;
+    fn state(&self) -> &GameState;
+    fn guess(&mut self, guessed_word: &str) -> WordValidation;
+
+    fn status(&self) -> GameStatus {
+        self.state().status(self.params())
+    }
+
+    fn reveal_lies(&self) -> Option<Vec<Lie>> {
+        (self.status() != GameStatus::Playing).then(|| self.state().lies())
+    }
+
+    fn contradictions(&self, word: &str) -> Vec<Contradiction> {
+        explain(
+            self.params().language,
+            &self.state().displayed_guesses,
+            word,
+        )
+    }
+
+    fn undo(&mut self) -> bool {
+        false
+    }
+
+    fn hint(&mut self, _kind: HintKind) -> Option<Hint> {
+        None
+    }
+
+    fn save(&self) -> Option<SavedGame> {
+        None
+    }
+
+    fn share(&self, _options: &ShareOptions) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::knowledge::Knowledge;
+use super::language::Language;
+use super::types::WordMatch;
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HardModeViolation {
+    MissingExact { pos: usize, c: char },
+    MissingLetter { c: char, count: usize },
+}
+
+impl Display for HardModeViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingExact { pos, c } => write!(
+                f,
+                "{} letter must be {}",
+                ordinal(pos + 1),
+                c.to_uppercase()
+            ),
+            Self::MissingLetter { c, count: 1 } => write!(f, "must contain {}", c.to_uppercase()),
+            Self::MissingLetter { c, count } => {
+                write!(f, "must contain {} {}s", count, c.to_uppercase())
+            }
+        }
+    }
+}
+
+pub fn check_hard_mode(
+    language: Language,
+    prev_guesses: &[WordMatch],
+    guess: &str,
+) -> Option<HardModeViolation> {
+    let knowledge = Knowledge::from_rows_in(language, prev_guesses);
+    let guess_chars: Vec<char> = guess.chars().map(|c| language.fold_char(c)).collect();
+
+    for (pos, fixed) in knowledge.fixed.iter().enumerate() {
+        if let Some(c) = fixed.iter().find(|c| guess_chars.get(pos) != Some(c)) {
+            return Some(HardModeViolation::MissingExact { pos, c: *c });
+        }
+    }
+
+    for (c, count) in knowledge.min.iter() {
+        let found = guess_chars.iter().filter(|x| *x == c).count();
+        if found < *count {
+            return Some(HardModeViolation::MissingLetter {
+                c: *c,
+                count: *count,
+            });
+        }
+    }
+
+    None
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", n, suffix)
+}
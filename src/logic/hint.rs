@@ -0,0 +1,172 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::knowledge::Knowledge;
+use super::language::Language;
+use super::state::GameStatus;
+use super::types::CharAlignment;
+use super::wordle::Wordle;
+use crate::solver::entropy::GlobalShrinkingEntropySolver;
+use crate::solver::positional::PositionalSolver;
+use crate::solver::solvers::Solver;
+
+const ENTROPY_HINT_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HintKind {
+    RevealPosition,
+    PresentLetter,
+    SuggestWord,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    Position { pos: usize, c: char },
+    Letter(char),
+    Word(String),
+}
+
+impl Hint {
+    pub fn allows(&self, language: Language, word: &str) -> bool {
+        let word = language.fold(word);
+
+        match self {
+            Self::Position { pos, c } => word.chars().nth(*pos) == Some(language.fold_char(*c)),
+            Self::Letter(c) => word.contains(language.fold_char(*c)),
+            Self::Word(_) => true,
+        }
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Position { pos, c } => {
+                write!(f, "Letter {} is {}", pos + 1, c.to_uppercase())
+            }
+            Self::Letter(c) => write!(f, "The word contains {}", c.to_uppercase()),
+            Self::Word(word) => write!(f, "Try {}", word.to_uppercase()),
+        }
+    }
+}
+
+impl Wordle {
+    pub fn hint(&mut self, kind: HintKind) -> Option<Hint> {
+        if self.status() != GameStatus::Playing {
+            return None;
+        }
+
+        let hint = match kind {
+            HintKind::RevealPosition => self.reveal_position(),
+            HintKind::PresentLetter => self.present_letter(),
+            HintKind::SuggestWord => self.suggest_word(),
+        }?;
+
+        self.state.hints.push(hint.clone());
+        Some(hint)
+    }
+
+    pub fn candidates(&self) -> Vec<Arc<str>> {
+        let language = self.params.language;
+        let knowledge = Knowledge::from_rows_in(language, &self.state.prev_guesses);
+
+        self.params
+            .answer_wordlist
+            .iter()
+            .filter(|word| knowledge.is_consistent(&language.fold(word)))
+            .filter(|word| !self.state.prev_guesses.iter().any(|x| *x.word == ***word))
+            .filter(|word| self.state.hints.iter().all(|x| x.allows(language, word)))
+            .cloned()
+            .collect()
+    }
+
+    fn reveal_position(&self) -> Option<Hint> {
+        let known: Vec<usize> = self
+            .state
+            .prev_guesses
+            .iter()
+            .flat_map(|x| {
+                x.aligns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, align)| **align == CharAlignment::Exact)
+                    .map(|(pos, _)| pos)
+            })
+            .chain(self.state.hints.iter().filter_map(|x| match x {
+                Hint::Position { pos, .. } => Some(*pos),
+                _ => None,
+            }))
+            .collect();
+
+        self.state
+            .target_word
+            .chars()
+            .enumerate()
+            .find(|(pos, _)| !known.contains(pos))
+            .map(|(pos, c)| Hint::Position { pos, c })
+    }
+
+    fn present_letter(&self) -> Option<Hint> {
+        let language = self.params.language;
+        let known: Vec<char> = self
+            .state
+            .prev_guesses
+            .iter()
+            .flat_map(|x| x.char_matches())
+            .filter(|x| x.align != CharAlignment::NotFound)
+            .map(|x| x.c)
+            .chain(self.state.hints.iter().filter_map(|x| match x {
+                Hint::Position { c, .. } | Hint::Letter(c) => Some(*c),
+                _ => None,
+            }))
+            .map(|c| language.fold_char(c))
+            .collect();
+
+        self.state
+            .target_word
+            .chars()
+            .find(|c| !known.contains(&language.fold_char(*c)))
+            .map(Hint::Letter)
+    }
+
+    fn suggest_word(&self) -> Option<Hint> {
+        let candidates = self.candidates();
+
+        let guess = if candidates.len() <= ENTROPY_HINT_LIMIT {
+            GlobalShrinkingEntropySolver::new(&candidates).guess()
+        } else {
+            PositionalSolver::new(&candidates).guess()
+        };
+
+        guess.map(Hint::Word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::game::Game;
+    use crate::logic::params::GameParameters;
+    use crate::logic::wordlist::WordList;
+
+    #[test]
+    fn candidates_fold_accents() {
+        let words = WordList::new(["limon", "ratón", "salón", "melón"]);
+        let params = GameParameters {
+            language: Language::Spanish,
+            ..GameParameters::with_wordlists(&words, &words, (5, 5))
+        };
+        let mut wordle = Wordle::new_game(params, "ratón".to_string());
+
+        wordle.guess("limon");
+
+        assert_eq!(wordle.candidates(), vec!["ratón".into()]);
+        assert!(wordle.contradictions("ratón").is_empty());
+        assert_eq!(
+            wordle.hint(HintKind::SuggestWord),
+            Some(Hint::Word("ratón".to_string()))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::knowledge::Knowledge;
+use super::types::WordMatch;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum LetterStatus {
+    #[default]
+    Unknown,
+    Absent,
+    Present,
+    Correct,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LetterInfo {
+    pub status: LetterStatus,
+    pub positions: BTreeSet<usize>,
+    pub excluded: BTreeSet<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyboardState {
+    knowledge: Knowledge,
+}
+
+impl KeyboardState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_rows<'a>(rows: impl IntoIterator<Item = &'a WordMatch>) -> Self {
+        Self {
+            knowledge: Knowledge::from_rows(rows),
+        }
+    }
+
+    pub fn update(&mut self, matches: &WordMatch) {
+        self.knowledge.add(matches);
+    }
+
+    pub fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
+
+    pub fn status(&self, c: char) -> LetterStatus {
+        let knowledge = &self.knowledge;
+
+        if knowledge.fixed.iter().any(|x| x.contains(&c)) {
+            LetterStatus::Correct
+        } else if knowledge.min.get(&c).map(|x| *x > 0).unwrap_or(false) {
+            LetterStatus::Present
+        } else if knowledge.is_absent(c) {
+            LetterStatus::Absent
+        } else {
+            LetterStatus::Unknown
+        }
+    }
+
+    pub fn letter(&self, c: char) -> Option<LetterInfo> {
+        let status = self.status(c);
+
+        (status != LetterStatus::Unknown).then(|| LetterInfo {
+            status,
+            positions: self.knowledge.fixed_positions(c),
+            excluded: self.knowledge.excluded_positions(c),
+        })
+    }
+
+    pub fn letters(&self) -> impl Iterator<Item = (char, LetterInfo)> + '_ {
+        self.knowledge
+            .letters()
+            .into_iter()
+            .filter_map(|c| Some((c, self.letter(c)?)))
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::language::Language;
+use super::types::{CharAlignment, WordMatch};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Knowledge {
+    pub size: Option<usize>,
+    pub fixed: Vec<BTreeSet<char>>,
+    pub excluded: Vec<BTreeSet<char>>,
+    pub min: BTreeMap<char, usize>,
+    pub max: BTreeMap<char, usize>,
+}
+
+impl Knowledge {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_rows<'a>(rows: impl IntoIterator<Item = &'a WordMatch>) -> Self {
+        let mut knowledge = Self::new();
+        rows.into_iter().for_each(|x| knowledge.add(x));
+
+        knowledge
+    }
+
+    pub fn from_rows_in<'a>(
+        language: Language,
+        rows: impl IntoIterator<Item = &'a WordMatch>,
+    ) -> Self {
+        let mut knowledge = Self::new();
+        rows.into_iter().for_each(|x| {
+            knowledge.add(&WordMatch {
+                word: language.fold(&x.word),
+                aligns: x.aligns.clone(),
+            })
+        });
+
+        knowledge
+    }
+
+    pub fn add(&mut self, matches: &WordMatch) {
+        let length = matches.aligns.len();
+        self.size.get_or_insert(length);
+
+        if self.fixed.len() < length {
+            self.fixed.resize(length, BTreeSet::new());
+            self.excluded.resize(length, BTreeSet::new());
+        }
+
+        let mut found: BTreeMap<char, usize> = BTreeMap::new();
+        let mut absent: BTreeSet<char> = BTreeSet::new();
+
+        for (pos, cmatch) in matches.char_matches().enumerate() {
+            match cmatch.align {
+                CharAlignment::Exact => {
+                    self.fixed[pos].insert(cmatch.c);
+                    *found.entry(cmatch.c).or_insert(0) += 1;
+                }
+                CharAlignment::Misplaced => {
+                    self.excluded[pos].insert(cmatch.c);
+                    *found.entry(cmatch.c).or_insert(0) += 1;
+                }
+                CharAlignment::NotFound => {
+                    self.excluded[pos].insert(cmatch.c);
+                    absent.insert(cmatch.c);
+                }
+            }
+        }
+
+        for c in absent {
+            let count = *found.get(&c).unwrap_or(&0);
+            let known = self.max.entry(c).or_insert(count);
+            *known = count.min(*known);
+        }
+
+        for (c, count) in found {
+            let known = self.min.entry(c).or_insert(0);
+            *known = count.max(*known);
+        }
+    }
+
+    pub fn is_consistent(&self, word: &str) -> bool {
+        let mut length = 0;
+        let mut counts: HashMap<char, usize> = HashMap::new();
+
+        for (pos, c) in word.chars().enumerate() {
+            let fixed = self.fixed.get(pos).map(|x| x.iter().all(|x| *x == c));
+            let excluded = self.excluded.get(pos).map(|x| x.contains(&c));
+
+            if fixed == Some(false) || excluded == Some(true) {
+                return false;
+            }
+
+            *counts.entry(c).or_insert(0) += 1;
+            length += 1;
+        }
+
+        if self.size.map(|x| x != length).unwrap_or(false) {
+            return false;
+        }
+
+        let count = |c: &char| *counts.get(c).unwrap_or(&0);
+
+        self.min.iter().all(|(c, min)| count(c) >= *min)
+            && self.max.iter().all(|(c, max)| count(c) <= *max)
+    }
+
+    pub fn is_absent(&self, c: char) -> bool {
+        self.max.get(&c) == Some(&0)
+    }
+
+    pub fn fixed_positions(&self, c: char) -> BTreeSet<usize> {
+        positions_of(&self.fixed, c)
+    }
+
+    pub fn excluded_positions(&self, c: char) -> BTreeSet<usize> {
+        positions_of(&self.excluded, c)
+    }
+
+    pub fn letters(&self) -> BTreeSet<char> {
+        self.fixed
+            .iter()
+            .chain(self.excluded.iter())
+            .flatten()
+            .chain(self.min.keys())
+            .chain(self.max.keys())
+            .copied()
+            .collect()
+    }
+}
+
+fn positions_of(sets: &[BTreeSet<char>], c: char) -> BTreeSet<usize> {
+    sets.iter()
+        .enumerate()
+        .filter(|(_, x)| x.contains(&c))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::params::GameParameters;
+    use crate::logic::wordle::match_word;
+
+    #[test]
+    fn single_row_matches_match_word() {
+        let answers = GameParameters::default().answer_wordlist;
+
+        for target in answers.iter().step_by(151) {
+            for guess in answers.iter().step_by(197) {
+                let matches = match_word(target, guess);
+                let knowledge = Knowledge::from_rows([&matches]);
+
+                for word in answers.iter() {
+                    assert_eq!(
+                        knowledge.is_consistent(word),
+                        match_word(word, guess).aligns == matches.aligns,
+                        "target {}, guess {}, word {}",
+                        target,
+                        guess,
+                        word
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn displayed_rows_keep_letter_bounds_ordered() {
+        let matches = WordMatch {
+            word: "eerie".to_string(),
+            aligns: vec![
+                CharAlignment::NotFound,
+                CharAlignment::Misplaced,
+                CharAlignment::NotFound,
+                CharAlignment::NotFound,
+                CharAlignment::NotFound,
+            ],
+        };
+        let knowledge = Knowledge::from_rows([&matches]);
+
+        assert_eq!(knowledge.min.get(&'e'), Some(&1));
+        assert_eq!(knowledge.max.get(&'e'), Some(&1));
+        assert!(knowledge.is_consistent("sheds"));
+        assert!(!knowledge.is_consistent("sheep"));
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+    French,
+}
+
+impl Language {
+    pub fn fold_char(&self, c: char) -> char {
+        match self {
+            Self::English => c,
+            Self::Spanish => match c {
+                'á' => 'a',
+                'é' => 'e',
+                'í' => 'i',
+                'ó' => 'o',
+                'ú' | 'ü' => 'u',
+                _ => c,
+            },
+            Self::German => c,
+            Self::French => match c {
+                'à' | 'â' | 'ä' => 'a',
+                'ç' => 'c',
+                'é' | 'è' | 'ê' | 'ë' => 'e',
+                'î' | 'ï' => 'i',
+                'ô' | 'ö' => 'o',
+                'ù' | 'û' | 'ü' => 'u',
+                'ÿ' => 'y',
+                _ => c,
+            },
+        }
+    }
+
+    pub fn fold(&self, word: &str) -> String {
+        word.chars().map(|c| self.fold_char(c)).collect()
+    }
+
+    pub fn folds_accents(&self) -> bool {
+        matches!(self, Self::Spanish | Self::French)
+    }
+}
+
+pub fn letter_count(word: &str) -> usize {
+    word.chars().count()
+}
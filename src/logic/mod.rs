@@ -1,9 +1,51 @@
+pub mod absurdle;
+pub mod challenge;
+pub mod clock;
+pub mod daily;
+pub mod explain;
+pub mod game;
+pub mod hard_mode;
+mod hash;
+pub mod hint;
+pub mod keyboard;
+pub mod knowledge;
+pub mod language;
+pub mod multi;
 pub mod mutator;
 pub mod params;
+pub mod race;
+pub mod replay;
+pub mod save;
+pub mod score;
+pub mod share;
 pub mod state;
+pub mod stats;
 pub mod types;
+pub mod undo;
 pub mod wordle;
+pub mod wordlist;
 
-pub use params::GameParameters;
+pub use absurdle::Absurdle;
+pub use challenge::Challenge;
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use daily::PuzzleDate;
+pub use explain::{Contradiction, ContradictionKind};
+pub use game::Game;
+pub use hard_mode::HardModeViolation;
+pub use hint::{Hint, HintKind};
+pub use keyboard::{KeyboardState, LetterInfo, LetterStatus};
+pub use knowledge::Knowledge;
+pub use language::Language;
+pub use multi::{BoardResult, MultiWordValidation, MultiWordle};
+pub use params::{GameParameters, GamePreset};
+pub use race::{Player, PlayerResult, Race, RaceMode};
+pub use replay::Replay;
+pub use save::SavedGame;
+pub use score::{GameScore, ScoringConfig, Session};
+pub use share::{Palette, ShareGrid, ShareOptions};
+pub use state::{GameStatus, Lie, LossReason};
+pub use stats::{GameRecord, Statistics};
 pub use types::{CharAlignment, CharMatch, WordMatch};
+pub use undo::UndoPolicy;
 pub use wordle::{GuessResult, InvalidationReason, WordValidation, Wordle};
+pub use wordlist::WordList;
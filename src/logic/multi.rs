@@ -0,0 +1,183 @@
+use super::language::letter_count;
+use super::mutator::RowContext;
+use super::params::GameParameters;
+use super::state::{GameState, GameStatus, Lie, LossReason};
+use super::types::WordMatch;
+use super::wordle::{match_word_in, validate_guess, GuessResult, InvalidationReason};
+use rand::prelude::IteratorRandom;
+use rand::Rng;
+
+#[derive(Debug)]
+pub enum BoardResult {
+    AlreadySolved,
+    Guessed(GuessResult, WordMatch),
+}
+
+#[derive(Debug)]
+pub enum MultiWordValidation {
+    Invalid(InvalidationReason, String),
+    Valid(Vec<BoardResult>),
+    GameOver(GameStatus),
+}
+
+pub struct MultiWordle {
+    pub params: GameParameters,
+    pub boards: Vec<GameState>,
+    pub guesses: Vec<String>,
+}
+
+impl MultiWordle {
+    pub fn new_random_game(params: GameParameters, board_count: usize, rng: &mut impl Rng) -> Self {
+        let targets = params
+            .answer_wordlist
+            .iter()
+            .choose_multiple(rng, board_count)
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect();
+
+        Self::new_game(params, targets)
+    }
+
+    pub fn new_game(mut params: GameParameters, targets: Vec<String>) -> Self {
+        params.reseed_mutator();
+
+        Self {
+            boards: targets
+                .into_iter()
+                .map(|target| GameState::new_game(&params, target))
+                .collect(),
+            guesses: Vec::new(),
+            params,
+        }
+    }
+
+    pub fn restart(self, board_count: usize, rng: &mut impl Rng) -> Self {
+        let params = self.params;
+
+        Self::new_random_game(params, board_count, rng)
+    }
+
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.solved_at(board).is_some()
+    }
+
+    pub fn solved_at(&self, board: usize) -> Option<usize> {
+        let state = &self.boards[board];
+
+        state
+            .prev_guesses
+            .iter()
+            .position(|x| x.word == state.target_word)
+            .map(|x| x + 1)
+    }
+
+    pub fn board_results(&self) -> Vec<Option<usize>> {
+        (0..self.boards.len()).map(|x| self.solved_at(x)).collect()
+    }
+
+    pub fn reveal_lies(&self) -> Option<Vec<Vec<Lie>>> {
+        if self.status() == GameStatus::Playing {
+            return None;
+        }
+
+        Some(self.boards.iter().map(|x| x.lies()).collect())
+    }
+
+    pub fn status(&self) -> GameStatus {
+        let last_solve = self
+            .board_results()
+            .into_iter()
+            .collect::<Option<Vec<usize>>>()
+            .and_then(|x| x.into_iter().max());
+
+        if let Some(last_solve) = last_solve {
+            if self.params.tries.map(|x| last_solve <= x).unwrap_or(true) {
+                return GameStatus::Won;
+            }
+        }
+
+        let time_expired = self
+            .boards
+            .first()
+            .map(|state| state.timed_out(&self.params))
+            .unwrap_or(false);
+
+        if time_expired {
+            return GameStatus::Lost(LossReason::Timeout);
+        }
+
+        let no_tries_left = self
+            .params
+            .tries
+            .map(|x| self.guesses.len() >= x)
+            .unwrap_or(false);
+
+        if no_tries_left {
+            return GameStatus::Lost(LossReason::NoTriesLeft);
+        }
+
+        GameStatus::Playing
+    }
+
+    pub fn guess(&mut self, guessed_word: &str) -> MultiWordValidation {
+        match self.status() {
+            GameStatus::Playing => {}
+            status => return MultiWordValidation::GameOver(status),
+        }
+
+        let canonical = self.params.find_word(guessed_word);
+        let guessed_word = canonical.as_deref().unwrap_or(guessed_word);
+        let unsolved: Vec<usize> = (0..self.boards.len())
+            .filter(|x| !self.is_solved(*x))
+            .collect();
+
+        for board in unsolved.iter() {
+            let state = &self.boards[*board];
+            if let Some(reason) =
+                validate_guess(&self.params, &state.displayed_guesses, guessed_word)
+            {
+                return MultiWordValidation::Invalid(reason, guessed_word.to_string());
+            } else if letter_count(guessed_word) != letter_count(&state.target_word) {
+                return MultiWordValidation::Invalid(
+                    InvalidationReason::WrongLength,
+                    guessed_word.to_string(),
+                );
+            }
+        }
+
+        self.guesses.push(guessed_word.to_string());
+
+        let language = self.params.language;
+        let mutator = &mut self.params.mutator;
+        let results = self
+            .boards
+            .iter_mut()
+            .enumerate()
+            .map(|(board, state)| {
+                if !unsolved.contains(&board) {
+                    return BoardResult::AlreadySolved;
+                }
+
+                let matches = match_word_in(language, &state.target_word, guessed_word);
+
+                if state.target_word == guessed_word {
+                    state.push_guess(matches.clone(), matches.clone());
+                    return BoardResult::Guessed(GuessResult::Correct, matches);
+                }
+
+                let displayed = mutator.mutate(&RowContext {
+                    guess: guessed_word,
+                    target: &state.target_word,
+                    matches: &matches,
+                    prev_rows: &state.prev_guesses,
+                });
+                state.push_guess(matches, displayed.clone());
+
+                BoardResult::Guessed(GuessResult::Wrong, displayed)
+            })
+            .collect();
+
+        MultiWordValidation::Valid(results)
+    }
+}
@@ -1,16 +1,83 @@
-use super::CharAlignment;
+use super::hash::{fnv1a, splitmix64};
+use super::{CharAlignment, WordMatch};
+use anyhow::{ensure, Result};
 use rand::{prelude::StdRng, thread_rng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
-pub trait Mutator {
-    fn mutate(&mut self, cmatch: CharAlignment) -> CharAlignment;
+pub struct RowContext<'a> {
+    pub guess: &'a str,
+    pub target: &'a str,
+    pub matches: &'a WordMatch,
+    pub prev_rows: &'a [WordMatch],
+}
+
+pub trait Mutator: Send {
+    fn mutate(&mut self, row: &RowContext) -> WordMatch;
+    fn config(&self) -> MutatorConfig;
+
+    fn reseed(&mut self, _seed: u64) {}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MutatorConfig {
+    Noop,
+    StepProb {
+        exact: f64,
+        misplaced: f64,
+        not_found: f64,
+    },
+    Fibble,
+}
+
+impl MutatorConfig {
+    pub fn build(&self) -> Box<dyn Mutator> {
+        match *self {
+            Self::Noop => Box::new(NoopMutator {}),
+            Self::StepProb {
+                exact,
+                misplaced,
+                not_found,
+            } => Box::new(StepProbMutator::new(
+                StdRng::from_rng(thread_rng())
+                    .expect("Step probability mutator failed to initalize rng"),
+                exact,
+                misplaced,
+                not_found,
+            )),
+            Self::Fibble => Box::new(FibbleMutator::default()),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Self::StepProb {
+            exact,
+            misplaced,
+            not_found,
+        } = *self
+        {
+            for prob in [exact, misplaced, not_found] {
+                ensure!(
+                    (0.0..=1.0).contains(&prob),
+                    "Mutator probability {} is outside [0, 1]",
+                    prob
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Default)]
 pub struct NoopMutator {}
 
 impl Mutator for NoopMutator {
-    fn mutate(&mut self, cmatch: CharAlignment) -> CharAlignment {
-        cmatch
+    fn mutate(&mut self, row: &RowContext) -> WordMatch {
+        row.matches.clone()
+    }
+
+    fn config(&self) -> MutatorConfig {
+        MutatorConfig::Noop
     }
 }
 
@@ -36,25 +103,8 @@ where
             not_found,
         }
     }
-}
 
-impl Default for StepProbMutator<StdRng> {
-    fn default() -> Self {
-        Self::new(
-            StdRng::from_rng(thread_rng())
-                .expect("Step probability mutator failed to initalize rng"),
-            0.2,
-            0.4,
-            0.2,
-        )
-    }
-}
-
-impl<R> Mutator for StepProbMutator<R>
-where
-    R: Rng,
-{
-    fn mutate(&mut self, cmatch: CharAlignment) -> CharAlignment {
+    fn mutate_align(&mut self, cmatch: CharAlignment) -> CharAlignment {
         let prob = match cmatch {
             CharAlignment::Exact => self.exact,
             CharAlignment::Misplaced => self.misplaced,
@@ -80,3 +130,134 @@ where
         }
     }
 }
+
+impl Default for StepProbMutator<StdRng> {
+    fn default() -> Self {
+        Self::new(
+            StdRng::from_rng(thread_rng())
+                .expect("Step probability mutator failed to initalize rng"),
+            0.2,
+            0.4,
+            0.2,
+        )
+    }
+}
+
+impl<R> Mutator for StepProbMutator<R>
+where
+    R: Rng + SeedableRng + Send,
+{
+    fn mutate(&mut self, row: &RowContext) -> WordMatch {
+        let mut matches = row.matches.clone();
+        matches
+            .aligns
+            .iter_mut()
+            .for_each(|x| *x = self.mutate_align(*x));
+
+        matches
+    }
+
+    fn config(&self) -> MutatorConfig {
+        MutatorConfig::StepProb {
+            exact: self.exact,
+            misplaced: self.misplaced,
+            not_found: self.not_found,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+pub struct FibbleMutator {
+    seed: u64,
+}
+
+impl FibbleMutator {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl Default for FibbleMutator {
+    fn default() -> Self {
+        Self::new(thread_rng().gen())
+    }
+}
+
+impl Mutator for FibbleMutator {
+    fn mutate(&mut self, row: &RowContext) -> WordMatch {
+        let mut matches = row.matches.clone();
+        if matches.aligns.is_empty() {
+            return matches;
+        }
+
+        // The lie only depends on the seed and the row, so replaying a game shows the same lies
+        let mut state = self.seed
+            ^ fnv1a(row.target.as_bytes())
+            ^ fnv1a(row.guess.as_bytes()).rotate_left(32)
+            ^ row.prev_rows.len() as u64;
+        let position = (splitmix64(&mut state) % matches.aligns.len() as u64) as usize;
+        let flip = splitmix64(&mut state) & 1 == 0;
+
+        let align = &mut matches.aligns[position];
+        *align = match (*align, flip) {
+            (CharAlignment::Exact, true) | (CharAlignment::NotFound, false) => {
+                CharAlignment::Misplaced
+            }
+            (CharAlignment::Exact, false) | (CharAlignment::Misplaced, true) => {
+                CharAlignment::NotFound
+            }
+            (CharAlignment::Misplaced, false) | (CharAlignment::NotFound, true) => {
+                CharAlignment::Exact
+            }
+        };
+
+        matches
+    }
+
+    fn config(&self) -> MutatorConfig {
+        MutatorConfig::Fibble
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::params::GameParameters;
+    use crate::logic::state::GameStatus;
+    use crate::logic::wordle::Wordle;
+
+    #[test]
+    fn fibble_lies_once_per_wrong_row() {
+        let guesses = ["slate", "house", "eerie", "crank", "about", "crane"];
+
+        for seed in 0..20 {
+            let params = GameParameters {
+                mutator: MutatorConfig::Fibble.build(),
+                seed: Some(seed),
+                ..GameParameters::default()
+            };
+            let mut wordle = Wordle::new_game(params, "crane".to_string());
+            for guess in guesses {
+                wordle.guess(guess);
+            }
+            assert_eq!(wordle.status(), GameStatus::Won);
+
+            let lies = wordle.state.lies();
+            let rows: Vec<usize> = lies.iter().map(|x| x.row).collect();
+
+            assert_eq!(
+                rows,
+                (0..guesses.len() - 1).collect::<Vec<_>>(),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
@@ -1,33 +1,180 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Duration;
 
-use super::mutator::{Mutator, NoopMutator};
+use anyhow::{ensure, Result};
+use rand::{prelude::StdRng, thread_rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
-pub struct GameParameters<'a> {
-    pub guess_wordlist: HashSet<&'a str>,
-    pub answer_wordlist: Vec<&'a str>,
+use super::clock::{Clock, SystemClock};
+use super::language::Language;
+use super::mutator::{Mutator, MutatorConfig, NoopMutator};
+use super::undo::UndoPolicy;
+use super::wordlist::WordList;
+
+pub struct GameParameters {
+    pub guess_wordlist: HashSet<Arc<str>>,
+    pub answer_wordlist: Vec<Arc<str>>,
     pub word_size: (usize, usize),
 
     pub mutator: Box<dyn Mutator>,
     pub tries: Option<usize>,
     pub time_limit: Option<Duration>,
+    pub guess_time_limit: Option<Duration>,
+    pub hard_mode: bool,
+    pub undo: UndoPolicy,
+    pub seed: Option<u64>,
+    pub language: Language,
+    pub clock: Arc<dyn Clock>,
 }
 
-impl Default for GameParameters<'static> {
-    fn default() -> Self {
-        let word_size = (5, 5);
-        let filter_words =
-            |x: &'static str| Some(x).filter(|x| x.len() >= word_size.0 && x.len() <= word_size.1);
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamePreset {
+    pub word_size: (usize, usize),
+    pub tries: Option<usize>,
+    pub time_limit: Option<Duration>,
+    pub hard_mode: bool,
+    pub mutator: MutatorConfig,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub guess_time_limit: Option<Duration>,
+    #[serde(default)]
+    pub undo: UndoPolicy,
+}
 
-        let answer_wordlist: Vec<&str> = include_str!("../../words/answers.txt")
-            .lines()
-            .filter_map(filter_words)
-            .collect();
+impl GameParameters {
+    pub fn find_word(&self, word: &str) -> Option<Arc<str>> {
+        if let Some(found) = self.guess_wordlist.get(word) {
+            return Some(found.clone());
+        } else if !self.language.folds_accents() {
+            return None;
+        }
+
+        let folded = self.language.fold(word);
+        self.guess_wordlist
+            .iter()
+            .find(|x| self.language.fold(x) == folded)
+            .cloned()
+    }
+
+    pub fn preset(&self) -> GamePreset {
+        GamePreset {
+            word_size: self.word_size,
+            tries: self.tries,
+            time_limit: self.time_limit,
+            hard_mode: self.hard_mode,
+            mutator: self.mutator.config(),
+            seed: self.seed,
+            language: self.language,
+            guess_time_limit: self.guess_time_limit,
+            undo: self.undo,
+        }
+    }
+
+    pub fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => {
+                StdRng::from_rng(thread_rng()).expect("Game parameters failed to initalize rng")
+            }
+        }
+    }
+
+    pub fn reseed_mutator(&mut self) {
+        if let Some(seed) = self.seed {
+            self.mutator.reseed(seed);
+        }
+    }
+
+    pub fn from_preset(preset: &GamePreset) -> Self {
+        Self::with_word_size(preset.word_size).with_preset(preset)
+    }
+
+    pub fn with_preset(self, preset: &GamePreset) -> Self {
+        let mut params = Self {
+            mutator: preset.mutator.build(),
+            tries: preset.tries,
+            time_limit: preset.time_limit,
+            guess_time_limit: preset.guess_time_limit,
+            hard_mode: preset.hard_mode,
+            undo: preset.undo,
+            seed: preset.seed,
+            language: preset.language,
+            word_size: preset.word_size,
+            ..self
+        };
 
-        let guess_wordlist = include_str!("../../words/guesses.txt")
-            .lines()
-            .filter_map(filter_words)
-            .chain(answer_wordlist.clone())
+        params.reseed_mutator();
+        params
+    }
+
+    pub fn try_from_preset(preset: &GamePreset) -> Result<Self> {
+        preset.mutator.validate()?;
+        ensure!(
+            preset.word_size.0 <= preset.word_size.1,
+            "Invalid word size range {:?}",
+            preset.word_size
+        );
+
+        let params = Self::from_preset(preset);
+        ensure!(
+            !params.answer_wordlist.is_empty(),
+            "No words with a length in {:?}",
+            preset.word_size
+        );
+
+        Ok(params)
+    }
+
+    pub fn with_word_size(word_size: (usize, usize)) -> Self {
+        let answers = WordList::parse_sized(include_str!("../../words/answers.txt"), word_size);
+        let guesses = WordList::parse_sized(include_str!("../../words/guesses.txt"), word_size);
+
+        Self::with_wordlists(&answers, &guesses, word_size)
+    }
+
+    pub fn from_sources(
+        answers: Option<&str>,
+        guesses: Option<&str>,
+        word_size: (usize, usize),
+    ) -> Result<Self> {
+        if answers.is_none() && guesses.is_none() {
+            return Ok(Self::with_word_size(word_size));
+        }
+
+        let answers = match answers {
+            Some(source) => WordList::from_source(source)?,
+            None => WordList::default(),
+        };
+        let guesses = match guesses {
+            Some(source) => WordList::from_source(source)?,
+            None => answers.clone(),
+        };
+
+        Ok(Self::with_wordlists(&answers, &guesses, word_size))
+    }
+
+    pub fn with_wordlists(
+        answers: &WordList,
+        guesses: &WordList,
+        word_size: (usize, usize),
+    ) -> Self {
+        let guesses = guesses.filter_size(word_size);
+        let mut answers = answers.filter_size(word_size);
+
+        if answers.is_empty() {
+            answers = guesses.clone();
+        }
+
+        let answer_wordlist = answers.words().to_vec();
+        let guess_wordlist = guesses
+            .words()
+            .iter()
+            .chain(answer_wordlist.iter())
+            .cloned()
             .collect();
 
         Self {
@@ -38,6 +185,18 @@ impl Default for GameParameters<'static> {
             mutator: Box::new(NoopMutator {}),
             tries: Some(6),
             time_limit: None,
+            guess_time_limit: None,
+            hard_mode: false,
+            undo: UndoPolicy::default(),
+            seed: None,
+            language: Language::English,
+            clock: Arc::new(SystemClock),
         }
     }
 }
+
+impl Default for GameParameters {
+    fn default() -> Self {
+        Self::with_word_size((5, 5))
+    }
+}
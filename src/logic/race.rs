@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use super::params::GameParameters;
+use super::state::{GameState, GameStatus};
+use super::wordle::{guess_board, WordValidation};
+use rand::prelude::IteratorRandom;
+use rand::Rng;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaceMode {
+    Turns,
+    Relay,
+}
+
+pub struct Player {
+    pub name: String,
+    pub state: GameState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerResult {
+    pub player: usize,
+    pub name: String,
+    pub status: GameStatus,
+    pub guesses: usize,
+    pub time: Duration,
+}
+
+pub struct Race {
+    pub params: GameParameters,
+    pub players: Vec<Player>,
+    pub mode: RaceMode,
+    pub current: usize,
+}
+
+impl Race {
+    pub fn new_random_game(
+        params: GameParameters,
+        names: Vec<String>,
+        mode: RaceMode,
+        rng: &mut impl Rng,
+    ) -> Option<Self> {
+        let target = params.answer_wordlist.iter().choose(rng)?.to_string();
+
+        Self::new_game(params, target, names, mode)
+    }
+
+    pub fn new_game(
+        mut params: GameParameters,
+        target: String,
+        names: Vec<String>,
+        mode: RaceMode,
+    ) -> Option<Self> {
+        if names.len() < MIN_PLAYERS || names.len() > MAX_PLAYERS {
+            return None;
+        }
+
+        params.reseed_mutator();
+
+        let players = names
+            .into_iter()
+            .map(|name| {
+                let mut state = GameState::new_game(&params, target.clone());
+                state.start_clock();
+                state.pause();
+
+                Player { name, state }
+            })
+            .collect();
+
+        let mut race = Self {
+            params,
+            players,
+            mode,
+            current: 0,
+        };
+        race.players[0].state.unpause();
+
+        Some(race)
+    }
+
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current]
+    }
+
+    pub fn status(&self, player: usize) -> GameStatus {
+        self.players[player].state.status(&self.params)
+    }
+
+    pub fn is_over(&self) -> bool {
+        (0..self.players.len()).all(|x| self.status(x) != GameStatus::Playing)
+    }
+
+    pub fn guess(&mut self, guessed_word: &str) -> WordValidation {
+        let state = &mut self.players[self.current].state;
+        let result = guess_board(&mut self.params, state, guessed_word);
+
+        let finished = state.status(&self.params) != GameStatus::Playing;
+        match (&result, self.mode) {
+            (WordValidation::Invalid(_, _), _) => {}
+            (_, RaceMode::Turns) => self.next_turn(),
+            (_, RaceMode::Relay) if finished => self.next_turn(),
+            _ => {}
+        }
+
+        result
+    }
+
+    fn next_turn(&mut self) {
+        self.players[self.current].state.pause();
+
+        let count = self.players.len();
+        let next = (1..=count)
+            .map(|x| (self.current + x) % count)
+            .find(|x| self.status(*x) == GameStatus::Playing);
+
+        if let Some(next) = next {
+            self.current = next;
+            self.players[next].state.unpause();
+        }
+    }
+
+    pub fn results(&self) -> Vec<PlayerResult> {
+        let mut results: Vec<PlayerResult> = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(player, x)| PlayerResult {
+                player,
+                name: x.name.clone(),
+                status: x.state.status(&self.params),
+                guesses: x.state.prev_guesses.len(),
+                time: x.state.elapsed().unwrap_or(Duration::ZERO),
+            })
+            .collect();
+
+        results.sort_by(compare_results);
+        results
+    }
+
+    pub fn winners(&self) -> Vec<PlayerResult> {
+        let results = self.results();
+
+        match results.first() {
+            Some(best) if best.status == GameStatus::Won => results
+                .iter()
+                .filter(|x| compare_results(x, best) == Ordering::Equal)
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn compare_results(a: &PlayerResult, b: &PlayerResult) -> Ordering {
+    let lost = |x: &PlayerResult| x.status != GameStatus::Won;
+
+    lost(a)
+        .cmp(&lost(b))
+        .then(a.guesses.cmp(&b.guesses))
+        .then(a.time.cmp(&b.time))
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use super::params::{GameParameters, GamePreset};
+use super::wordle::{WordValidation, Wordle};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub target: String,
+    pub preset: GamePreset,
+    pub guesses: Vec<String>,
+}
+
+impl Replay {
+    pub fn record(wordle: &Wordle) -> Self {
+        Self {
+            seed: wordle.state.mutator_seed,
+            target: wordle.state.target_word.clone(),
+            preset: wordle.params.preset(),
+            guesses: wordle
+                .state
+                .prev_guesses
+                .iter()
+                .map(|x| x.word.clone())
+                .collect(),
+        }
+    }
+
+    pub fn play(&self) -> (Wordle, Vec<WordValidation>) {
+        let params = GameParameters::from_preset(&self.preset);
+
+        Wordle::replay(params, self.seed, self.target.clone(), &self.guesses)
+    }
+}
+
+impl Wordle {
+    pub fn replay(
+        mut params: GameParameters,
+        seed: u64,
+        target: String,
+        guesses: &[impl AsRef<str>],
+    ) -> (Self, Vec<WordValidation>) {
+        params.seed = Some(seed);
+
+        let mut wordle = Self::new_game(params, target);
+        let results = guesses
+            .iter()
+            .map(|guess| wordle.guess(guess.as_ref()))
+            .collect();
+
+        (wordle, results)
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use super::hint::Hint;
+use super::keyboard::KeyboardState;
+use super::params::{GameParameters, GamePreset};
+use super::state::GameState;
+use super::types::WordMatch;
+use super::wordle::Wordle;
+
+pub const SAVE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub version: u32,
+    pub preset: GamePreset,
+    pub target_word: String,
+    pub prev_guesses: Vec<WordMatch>,
+    #[serde(default)]
+    pub displayed_guesses: Vec<WordMatch>,
+    pub elapsed: Option<Duration>,
+    #[serde(default)]
+    pub guess_times: Vec<Duration>,
+    #[serde(default)]
+    pub undos: usize,
+    #[serde(default)]
+    pub hints: Vec<Hint>,
+    #[serde(default)]
+    pub mutator_seed: Option<u64>,
+    #[serde(default)]
+    pub variant: Option<String>,
+    #[serde(default)]
+    pub game_number: Option<usize>,
+    #[serde(default)]
+    pub answers: Option<String>,
+    #[serde(default)]
+    pub guesses: Option<String>,
+}
+
+impl SavedGame {
+    pub fn displayed_rows(&self) -> &[WordMatch] {
+        if self.displayed_guesses.len() == self.prev_guesses.len() {
+            &self.displayed_guesses
+        } else {
+            &self.prev_guesses
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let saved: Self = serde_json::from_str(json)?;
+
+        if saved.version != SAVE_VERSION {
+            bail!("Unsupported save version {}", saved.version);
+        }
+
+        Ok(saved)
+    }
+}
+
+impl Wordle {
+    pub fn save(&self) -> SavedGame {
+        SavedGame {
+            version: SAVE_VERSION,
+            preset: self.params.preset(),
+            target_word: self.state.target_word.clone(),
+            prev_guesses: self.state.prev_guesses.clone(),
+            displayed_guesses: self.state.displayed_guesses.clone(),
+            elapsed: self.state.elapsed(),
+            guess_times: self.state.guess_times.clone(),
+            undos: self.state.undos,
+            hints: self.state.hints.clone(),
+            mutator_seed: Some(self.state.mutator_seed),
+            variant: None,
+            game_number: None,
+            answers: None,
+            guesses: None,
+        }
+    }
+
+    pub fn resume(saved: &SavedGame) -> Self {
+        Self::resume_with_params(saved, GameParameters::from_preset(&saved.preset))
+    }
+
+    pub fn resume_with_params(saved: &SavedGame, params: GameParameters) -> Self {
+        let elapsed = saved.elapsed.unwrap_or(Duration::ZERO);
+        let mut state = GameState::with_elapsed(&params, saved.target_word.clone(), elapsed);
+        state.prev_guesses = saved.prev_guesses.clone();
+        state.displayed_guesses = saved.displayed_rows().to_vec();
+        state.keyboard = KeyboardState::from_rows(&state.displayed_guesses);
+        state.guess_times = if saved.guess_times.len() == saved.prev_guesses.len() {
+            saved.guess_times.clone()
+        } else {
+            vec![elapsed; saved.prev_guesses.len()]
+        };
+        state.undos = saved.undos;
+        state.hints = saved.hints.clone();
+        if let Some(seed) = saved.mutator_seed {
+            state.mutator_seed = seed;
+        }
+        state.update_status(&params);
+
+        let mut wordle = Self { params, state };
+        wordle.replay_mutator();
+        wordle
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::game::Game;
+use super::mutator::MutatorConfig;
+use super::state::GameStatus;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    pub win_points: u32,
+    pub unused_try_points: u32,
+    pub time_points: u32,
+    pub hint_penalty: u32,
+    pub hard_mode_multiplier: f64,
+    pub mutator_multiplier: f64,
+    pub streak_points: u32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            win_points: 1000,
+            unused_try_points: 100,
+            time_points: 250,
+            hint_penalty: 75,
+            hard_mode_multiplier: 1.5,
+            mutator_multiplier: 1.25,
+            streak_points: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameScore {
+    pub won: bool,
+    pub base: u32,
+    pub tries_bonus: u32,
+    pub time_bonus: u32,
+    pub hint_penalty: u32,
+    pub undo_penalty: u32,
+    pub multiplier: f64,
+    pub total: u32,
+}
+
+impl ScoringConfig {
+    pub fn score(&self, game: &dyn Game) -> GameScore {
+        let params = game.params();
+        let state = game.state();
+        let won = game.status() == GameStatus::Won;
+
+        let (base, tries_bonus, time_bonus) = if won {
+            let unused_tries = params
+                .tries
+                .map(|x| x.saturating_sub(state.prev_guesses.len()))
+                .unwrap_or(0);
+
+            let time_left = params
+                .time_limit
+                .zip(state.elapsed())
+                .map(|(limit, elapsed)| {
+                    limit.saturating_sub(elapsed).as_secs_f64() / limit.as_secs_f64()
+                })
+                .unwrap_or(0.0);
+
+            (
+                self.win_points,
+                unused_tries as u32 * self.unused_try_points,
+                (time_left * self.time_points as f64) as u32,
+            )
+        } else {
+            (0, 0, 0)
+        };
+
+        let hint_penalty = state.hints.len() as u32 * self.hint_penalty;
+        let undo_penalty = state.undos as u32 * params.undo.penalty;
+
+        let mut multiplier = 1.0;
+        if params.hard_mode {
+            multiplier *= self.hard_mode_multiplier;
+        }
+        if params.mutator.config() != MutatorConfig::Noop {
+            multiplier *= self.mutator_multiplier;
+        }
+
+        let raw = (base + tries_bonus + time_bonus).saturating_sub(hint_penalty + undo_penalty);
+
+        GameScore {
+            won,
+            base,
+            tries_bonus,
+            time_bonus,
+            hint_penalty,
+            undo_penalty,
+            multiplier,
+            total: (raw as f64 * multiplier).round() as u32,
+        }
+    }
+}
+
+impl Display for GameScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Score: {}", self.total)?;
+
+        if self.total > 0 || self.hint_penalty > 0 || self.undo_penalty > 0 {
+            write!(
+                f,
+                " ({} + {} tries + {} time - {} hints - {} undos) x{:.2}",
+                self.base,
+                self.tries_bonus,
+                self.time_bonus,
+                self.hint_penalty,
+                self.undo_penalty,
+                self.multiplier
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub config: ScoringConfig,
+    pub games: Vec<GameScore>,
+}
+
+impl Session {
+    pub fn new(config: ScoringConfig) -> Self {
+        Self {
+            config,
+            games: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, game: &dyn Game) -> &GameScore {
+        let score = self.config.score(game);
+        self.games.push(score);
+
+        self.games.last().unwrap()
+    }
+
+    pub fn wins(&self) -> usize {
+        self.games.iter().filter(|x| x.won).count()
+    }
+
+    pub fn streak_bonus(&self) -> u32 {
+        self.games
+            .iter()
+            .scan(0_u32, |streak, game| {
+                *streak = if game.won { *streak + 1 } else { 0 };
+                Some(streak.saturating_sub(1) * self.config.streak_points)
+            })
+            .sum()
+    }
+
+    pub fn total(&self) -> u32 {
+        self.games.iter().map(|x| x.total).sum::<u32>() + self.streak_bonus()
+    }
+}
+
+impl Display for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Session: {} games, {} wins, {} points ({} streak bonus)",
+            self.games.len(),
+            self.wins(),
+            self.total(),
+            self.streak_bonus()
+        )
+    }
+}
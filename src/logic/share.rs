@@ -0,0 +1,237 @@
+use anyhow::{anyhow, bail, Result};
+
+use super::multi::MultiWordle;
+use super::mutator::MutatorConfig;
+use super::types::{CharAlignment, WordMatch};
+use super::wordle::Wordle;
+
+const BOARD_FILLER: char = '➖';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Standard,
+    HighContrast,
+}
+
+impl Palette {
+    pub fn tile(&self, align: CharAlignment) -> char {
+        match (self, align) {
+            (Self::Standard, CharAlignment::Exact) => '🟩',
+            (Self::Standard, CharAlignment::Misplaced) => '🟨',
+            (Self::HighContrast, CharAlignment::Exact) => '🟧',
+            (Self::HighContrast, CharAlignment::Misplaced) => '🟦',
+            (_, CharAlignment::NotFound) => '⬛',
+        }
+    }
+
+    pub fn parse_tile(c: char) -> Option<CharAlignment> {
+        match c {
+            '🟩' | '🟧' => Some(CharAlignment::Exact),
+            '🟨' | '🟦' => Some(CharAlignment::Misplaced),
+            '⬛' | '⬜' => Some(CharAlignment::NotFound),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShareOptions {
+    pub title: String,
+    pub game_number: Option<usize>,
+    pub palette: Palette,
+    pub mark_hard_mode: bool,
+}
+
+impl Default for ShareOptions {
+    fn default() -> Self {
+        Self {
+            title: "Jordle".to_string(),
+            game_number: None,
+            palette: Palette::Standard,
+            mark_hard_mode: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareGrid {
+    pub title: String,
+    pub game_number: Option<usize>,
+    pub scores: Vec<Option<usize>>,
+    pub tries: Option<usize>,
+    pub hard_mode: bool,
+    pub fuzzy: bool,
+    pub boards: Vec<Vec<Vec<CharAlignment>>>,
+}
+
+impl ShareGrid {
+    pub fn from_wordle(wordle: &Wordle, options: &ShareOptions) -> Self {
+        let rows = &wordle.state.displayed_guesses;
+        let score = wordle
+            .state
+            .prev_guesses
+            .iter()
+            .position(|x| x.word == wordle.state.target_word)
+            .map(|x| x + 1);
+
+        Self {
+            title: options.title.clone(),
+            game_number: options.game_number,
+            scores: vec![score],
+            tries: wordle.params.tries,
+            hard_mode: options.mark_hard_mode && wordle.params.hard_mode,
+            fuzzy: wordle.params.mutator.config() != MutatorConfig::Noop,
+            boards: vec![board_rows(rows)],
+        }
+    }
+
+    pub fn from_multi(multi: &MultiWordle, options: &ShareOptions) -> Self {
+        Self {
+            title: options.title.clone(),
+            game_number: options.game_number,
+            scores: multi.board_results(),
+            tries: multi.params.tries,
+            hard_mode: options.mark_hard_mode && multi.params.hard_mode,
+            fuzzy: multi.params.mutator.config() != MutatorConfig::Noop,
+            boards: multi
+                .boards
+                .iter()
+                .map(|x| board_rows(&x.displayed_guesses))
+                .collect(),
+        }
+    }
+
+    pub fn render(&self, palette: Palette) -> String {
+        let mut header = self.title.clone();
+
+        if let Some(number) = self.game_number {
+            header.push_str(&format!(" {}", number));
+        }
+
+        let scores: Vec<String> = self
+            .scores
+            .iter()
+            .map(|x| x.map(|x| x.to_string()).unwrap_or_else(|| "X".to_string()))
+            .collect();
+
+        header.push(' ');
+        if self.fuzzy {
+            header.push('~');
+        }
+        header.push_str(&scores.join("&"));
+
+        if let Some(tries) = self.tries {
+            header.push_str(&format!("/{}", tries));
+        }
+
+        if self.hard_mode {
+            header.push('*');
+        }
+
+        let row_count = self.boards.iter().map(|x| x.len()).max().unwrap_or(0);
+        let lines = (0..row_count).map(|row| {
+            self.boards
+                .iter()
+                .map(|board| match board.get(row) {
+                    Some(aligns) => aligns.iter().map(|x| palette.tile(*x)).collect(),
+                    None => {
+                        let width = board.first().map(|x| x.len()).unwrap_or(0);
+                        std::iter::repeat_n(BOARD_FILLER, width).collect::<String>()
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        });
+
+        std::iter::once(header)
+            .chain(std::iter::once(String::new()))
+            .chain(lines)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text.lines().map(|x| x.trim()).filter(|x| !x.is_empty());
+        let header = lines.next().ok_or_else(|| anyhow!("Share grid is empty"))?;
+
+        let mut tokens: Vec<&str> = header.split_whitespace().collect();
+        let mut score_token = tokens
+            .pop()
+            .ok_or_else(|| anyhow!("Share grid header is missing a score"))?;
+
+        let hard_mode = score_token.ends_with('*');
+        score_token = score_token.trim_end_matches('*');
+
+        let fuzzy = score_token.starts_with('~');
+        score_token = score_token.trim_start_matches('~');
+
+        let (score_part, tries) = match score_token.split_once('/') {
+            Some((scores, tries)) => (scores, Some(tries.parse()?)),
+            None => (score_token, None),
+        };
+
+        let scores = score_part
+            .split('&')
+            .map(|x| match x {
+                "X" | "x" => Ok(None),
+                _ => Ok(Some(x.parse()?)),
+            })
+            .collect::<Result<Vec<Option<usize>>>>()?;
+
+        let game_number = match tokens.last().map(|x| x.trim_start_matches('#').parse()) {
+            Some(Ok(number)) => {
+                tokens.pop();
+                Some(number)
+            }
+            _ => None,
+        };
+
+        let mut boards: Vec<Vec<Vec<CharAlignment>>> = vec![Vec::new(); scores.len()];
+        for line in lines {
+            let row_boards: Vec<&str> = line.split_whitespace().collect();
+            if row_boards.len() != boards.len() {
+                bail!("Expected {} boards in row {:?}", boards.len(), line);
+            }
+
+            for (board, tiles) in boards.iter_mut().zip(row_boards) {
+                if tiles.chars().all(|x| x == BOARD_FILLER) {
+                    continue;
+                }
+
+                let aligns = tiles
+                    .chars()
+                    .filter(|x| *x != '\u{fe0f}')
+                    .map(|x| Palette::parse_tile(x).ok_or_else(|| anyhow!("Unknown tile {:?}", x)))
+                    .collect::<Result<Vec<CharAlignment>>>()?;
+
+                board.push(aligns);
+            }
+        }
+
+        Ok(Self {
+            title: tokens.join(" "),
+            game_number,
+            scores,
+            tries,
+            hard_mode,
+            fuzzy,
+            boards,
+        })
+    }
+}
+
+fn board_rows(rows: &[WordMatch]) -> Vec<Vec<CharAlignment>> {
+    rows.iter().map(|x| x.aligns.clone()).collect()
+}
+
+impl Wordle {
+    pub fn share(&self, options: &ShareOptions) -> String {
+        ShareGrid::from_wordle(self, options).render(options.palette)
+    }
+}
+
+impl MultiWordle {
+    pub fn share(&self, options: &ShareOptions) -> String {
+        ShareGrid::from_multi(self, options).render(options.palette)
+    }
+}
@@ -1,20 +1,242 @@
-use std::time::Instant;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+
+use super::clock::Clock;
+use super::hint::Hint;
+use super::keyboard::KeyboardState;
 use super::params::GameParameters;
-use super::types::WordMatch;
+use super::types::{CharAlignment, WordMatch};
 
 pub struct GameState {
     pub prev_guesses: Vec<WordMatch>,
+    pub displayed_guesses: Vec<WordMatch>,
+    pub keyboard: KeyboardState,
     pub target_word: String,
-    pub start_time: Option<Instant>,
+    pub clock: Arc<dyn Clock>,
+    pub start_time: Option<Duration>,
+    pub time_offset: Duration,
+    pub last_guess_time: Duration,
+    pub guess_times: Vec<Duration>,
+    pub paused: bool,
+    pub undos: usize,
+    pub hints: Vec<Hint>,
+    pub mutator_seed: u64,
+    pub status: GameStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LossReason {
+    Timeout,
+    NoTriesLeft,
+    Resigned,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameStatus {
+    Playing,
+    Won,
+    Lost(LossReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lie {
+    pub row: usize,
+    pub pos: usize,
+    pub c: char,
+    pub truth: CharAlignment,
+    pub displayed: CharAlignment,
 }
 
 impl GameState {
     pub fn new_game(params: &GameParameters, target: String) -> Self {
+        Self::with_elapsed(params, target, Duration::ZERO)
+    }
+
+    pub fn with_elapsed(params: &GameParameters, target: String, elapsed: Duration) -> Self {
         Self {
             prev_guesses: Vec::new(),
+            displayed_guesses: Vec::new(),
+            keyboard: KeyboardState::new(),
             target_word: target,
-            start_time: params.time_limit.map(|_| Instant::now()),
+            clock: params.clock.clone(),
+            start_time: Some(params.clock.now()),
+            time_offset: elapsed,
+            last_guess_time: elapsed,
+            guess_times: Vec::new(),
+            paused: false,
+            undos: 0,
+            hints: Vec::new(),
+            mutator_seed: params.seed.unwrap_or_else(rand::random),
+            status: GameStatus::Playing,
+        }
+    }
+
+    pub fn elapsed(&self) -> Option<Duration> {
+        let start = self.start_time?;
+
+        if self.paused {
+            Some(self.time_offset)
+        } else {
+            Some(self.time_offset + self.clock.now().saturating_sub(start))
+        }
+    }
+
+    pub fn start_clock(&mut self) {
+        if self.start_time.is_none() {
+            self.start_time = Some(self.clock.now());
+            self.paused = false;
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(elapsed) = self.elapsed().filter(|_| !self.paused) {
+            self.time_offset = elapsed;
+            self.paused = true;
         }
     }
+
+    pub fn unpause(&mut self) {
+        if self.paused {
+            self.start_time = Some(self.clock.now());
+            self.paused = false;
+        }
+    }
+
+    pub fn remaining_time(&self, params: &GameParameters) -> Option<Duration> {
+        let elapsed = self.elapsed()?;
+
+        let game_remaining = params.time_limit.map(|x| x.saturating_sub(elapsed));
+        let guess_remaining = params
+            .guess_time_limit
+            .map(|x| x.saturating_sub(elapsed.saturating_sub(self.last_guess_time)));
+
+        match (game_remaining, guess_remaining) {
+            (Some(game), Some(guess)) => Some(game.min(guess)),
+            (game, guess) => game.or(guess),
+        }
+    }
+
+    pub fn timed_out(&self, params: &GameParameters) -> bool {
+        self.remaining_time(params) == Some(Duration::ZERO)
+    }
+
+    pub fn push_guess(&mut self, truth: WordMatch, displayed: WordMatch) {
+        self.keyboard.update(&displayed);
+        self.prev_guesses.push(truth);
+        self.displayed_guesses.push(displayed);
+        self.last_guess_time = self.elapsed().unwrap_or(Duration::ZERO);
+        self.guess_times.push(self.last_guess_time);
+    }
+
+    pub fn rewind(&mut self, guesses: usize) {
+        let elapsed = guesses
+            .checked_sub(1)
+            .and_then(|x| self.guess_times.get(x).copied())
+            .unwrap_or(Duration::ZERO);
+
+        self.prev_guesses.truncate(guesses);
+        self.displayed_guesses.truncate(guesses);
+        self.guess_times.truncate(guesses);
+        self.keyboard = KeyboardState::from_rows(&self.displayed_guesses);
+
+        if self.status != GameStatus::Playing {
+            self.status = GameStatus::Playing;
+            self.paused = false;
+        }
+
+        self.time_offset = elapsed;
+        self.last_guess_time = elapsed;
+        if self.start_time.is_some() {
+            self.start_time = Some(self.clock.now());
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.prev_guesses
+            .last()
+            .map(|x| x.word == self.target_word)
+            .unwrap_or(false)
+    }
+
+    pub fn status(&self, params: &GameParameters) -> GameStatus {
+        if self.status != GameStatus::Playing {
+            return self.status;
+        }
+
+        let no_tries_left = params
+            .tries
+            .map(|x| self.prev_guesses.len() >= x)
+            .unwrap_or(false);
+
+        if self.is_solved() {
+            GameStatus::Won
+        } else if no_tries_left {
+            GameStatus::Lost(LossReason::NoTriesLeft)
+        } else if self.timed_out(params) {
+            GameStatus::Lost(LossReason::Timeout)
+        } else {
+            GameStatus::Playing
+        }
+    }
+
+    pub fn resign(&mut self) {
+        if self.status == GameStatus::Playing {
+            self.status = GameStatus::Lost(LossReason::Resigned);
+            self.pause();
+        }
+    }
+
+    pub fn update_status(&mut self, params: &GameParameters) -> GameStatus {
+        self.status = self.status(params);
+        if self.status != GameStatus::Playing {
+            self.pause();
+        }
+
+        self.status
+    }
+
+    pub fn lies(&self) -> Vec<Lie> {
+        self.prev_guesses
+            .iter()
+            .zip(self.displayed_guesses.iter())
+            .enumerate()
+            .flat_map(|(row, (truth, displayed))| {
+                truth
+                    .char_matches()
+                    .zip(displayed.aligns.iter())
+                    .enumerate()
+                    .filter(|(_, (truth, displayed))| truth.align != **displayed)
+                    .map(move |(pos, (truth, displayed))| Lie {
+                        row,
+                        pos,
+                        c: truth.c,
+                        truth: truth.align,
+                        displayed: *displayed,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Display for Lie {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = |align: CharAlignment| match align {
+            CharAlignment::Exact => "correct",
+            CharAlignment::Misplaced => "misplaced",
+            CharAlignment::NotFound => "absent",
+        };
+
+        write!(
+            f,
+            "Row {}, letter {} ({}): shown {}, was {}",
+            self.row + 1,
+            self.pos + 1,
+            self.c.to_uppercase(),
+            name(self.displayed),
+            name(self.truth)
+        )
+    }
 }
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::game::Game;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub won: bool,
+    pub guesses: usize,
+    pub variant: String,
+    pub duration: Option<Duration>,
+    pub target: String,
+}
+
+impl GameRecord {
+    pub fn from_game(game: &dyn Game, variant: &str) -> Self {
+        let state = game.state();
+        Self {
+            won: state.is_solved(),
+            guesses: state.prev_guesses.len(),
+            variant: variant.to_string(),
+            duration: state.elapsed(),
+            target: state.target_word.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Statistics {
+    pub records: Vec<GameRecord>,
+}
+
+impl Statistics {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, self.to_json()?)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn record(&mut self, record: GameRecord) {
+        self.records.push(record);
+    }
+
+    pub fn played(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn wins(&self) -> usize {
+        self.records.iter().filter(|x| x.won).count()
+    }
+
+    pub fn win_percentage(&self) -> f32 {
+        if self.records.is_empty() {
+            return 0.0;
+        }
+
+        self.wins() as f32 * 100.0 / self.played() as f32
+    }
+
+    pub fn current_streak(&self) -> usize {
+        self.records.iter().rev().take_while(|x| x.won).count()
+    }
+
+    pub fn max_streak(&self) -> usize {
+        self.records
+            .iter()
+            .fold((0, 0), |(current, max), record| {
+                let current = if record.won { current + 1 } else { 0 };
+                (current, max.max(current))
+            })
+            .1
+    }
+
+    pub fn guess_distribution(&self) -> Vec<usize> {
+        let max_guesses = self
+            .records
+            .iter()
+            .filter(|x| x.won)
+            .map(|x| x.guesses)
+            .max()
+            .unwrap_or(0);
+
+        let mut dist = vec![0; max_guesses];
+        self.records
+            .iter()
+            .filter(|x| x.won && x.guesses > 0)
+            .for_each(|x| dist[x.guesses - 1] += 1);
+
+        dist
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Played: {}  Win %: {:.0}  Current streak: {}  Max streak: {}",
+            self.played(),
+            self.win_percentage(),
+            self.current_streak(),
+            self.max_streak()
+        )?;
+
+        let dist = self.guess_distribution();
+        let most = dist.iter().copied().max().unwrap_or(0).max(1);
+
+        writeln!(f, "Guess distribution:")?;
+        for (guesses, count) in dist.iter().enumerate() {
+            let bar = "#".repeat(count * 20 / most);
+            writeln!(f, "{:>2}: {} {}", guesses + 1, bar, count)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
-#[derive(Clone, Eq, PartialEq, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct WordMatch {
     pub word: String,
     pub aligns: Vec<CharAlignment>,
@@ -38,7 +40,7 @@ impl WordMatch {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum CharAlignment {
     NotFound,
     Misplaced,
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::mutator::RowContext;
+use super::wordle::Wordle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UndoPolicy {
+    pub allowed: bool,
+    pub penalty: u32,
+}
+
+impl UndoPolicy {
+    pub fn practice() -> Self {
+        Self {
+            allowed: true,
+            penalty: 0,
+        }
+    }
+}
+
+impl Wordle {
+    pub fn undo(&mut self) -> bool {
+        match self.state.prev_guesses.len().checked_sub(1) {
+            Some(guesses) => self.rewind(guesses),
+            None => false,
+        }
+    }
+
+    pub fn rewind(&mut self, guesses: usize) -> bool {
+        if !self.params.undo.allowed || guesses >= self.state.prev_guesses.len() {
+            return false;
+        }
+
+        self.state.rewind(guesses);
+        self.state.undos += 1;
+        self.replay_mutator();
+
+        true
+    }
+
+    pub(crate) fn replay_mutator(&mut self) {
+        self.params.mutator.reseed(self.state.mutator_seed);
+
+        let rows = &self.state.prev_guesses;
+        for (row, matches) in rows.iter().enumerate() {
+            if matches.word == self.state.target_word {
+                continue;
+            }
+
+            self.params.mutator.mutate(&RowContext {
+                guess: &matches.word,
+                target: &self.state.target_word,
+                matches,
+                prev_rows: &rows[..row],
+            });
+        }
+    }
+}
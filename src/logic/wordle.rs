@@ -1,42 +1,48 @@
+use super::game::Game;
+use super::hard_mode::{check_hard_mode, HardModeViolation};
+use super::hint::{Hint, HintKind};
+use super::language::{letter_count, Language};
+use super::mutator::RowContext;
+use super::params::GameParameters;
+use super::save::SavedGame;
+use super::share::ShareOptions;
+use super::state::{GameState, GameStatus};
 use super::types::WordMatch;
 use super::*;
-use super::{params::GameParameters, state::GameState};
 use rand::prelude::IteratorRandom;
-use rand::{thread_rng, Rng};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum InvalidationReason {
     WrongLength,
     UnknownWord,
     RepeatWord,
+    HardMode(HardModeViolation),
+    Paused,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum GuessResult {
     Correct,
     Wrong,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum WordValidation {
     Invalid(InvalidationReason, String),
     Valid(GuessResult, WordMatch),
+    GameOver(GameStatus),
 }
 
-#[derive(Debug)]
-pub enum GameEndTriggers {
-    Timeout,
-    NoTriesLeft,
-    StillPlaying,
-}
-
-pub struct Wordle<'a> {
-    pub params: GameParameters<'a>,
+pub struct Wordle {
+    pub params: GameParameters,
     pub state: GameState,
 }
 
-impl<'a> Wordle<'a> {
-    pub fn new_random_game(params: GameParameters<'a>, rng: &mut impl Rng) -> Self {
+impl Wordle {
+    pub fn new_random_game(params: GameParameters, rng: &mut impl Rng) -> Self {
         let target = params
             .answer_wordlist
             .iter()
@@ -47,36 +53,41 @@ impl<'a> Wordle<'a> {
         Self::new_game(params, target)
     }
 
-    pub fn check_state(&self) -> GameEndTriggers {
-        let time_expired = self
-            .params
-            .time_limit
-            .map(|dur| self.state.start_time.map(|start| dur < start.elapsed()))
-            .flatten()
-            .unwrap_or(false);
+    pub fn status(&self) -> GameStatus {
+        self.state.status(&self.params)
+    }
 
-        if time_expired {
-            return GameEndTriggers::Timeout;
-        }
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.state.remaining_time(&self.params)
+    }
 
-        let no_tries_left = self
-            .params
-            .tries
-            .map(|x| x >= self.state.prev_guesses.len())
-            .unwrap_or(false);
+    pub fn pause(&mut self) {
+        self.state.pause();
+    }
 
-        if no_tries_left {
-            return GameEndTriggers::NoTriesLeft;
-        }
+    pub fn unpause(&mut self) {
+        self.state.unpause();
+    }
 
-        GameEndTriggers::StillPlaying
+    pub fn resign(&mut self) {
+        self.state.resign();
     }
 
-    pub fn new_game(params: GameParameters<'a>, target: String) -> Self {
-        Self {
-            state: GameState::new_game(&params, target),
-            params,
-        }
+    pub fn is_paused(&self) -> bool {
+        self.state.paused
+    }
+
+    pub fn new_seeded_game(params: GameParameters) -> Self {
+        let mut rng = params.rng();
+
+        Self::new_random_game(params, &mut rng)
+    }
+
+    pub fn new_game(mut params: GameParameters, target: String) -> Self {
+        let state = GameState::new_game(&params, target);
+        params.mutator.reseed(state.mutator_seed);
+
+        Self { params, state }
     }
 
     pub fn restart(self, rng: &mut impl Rng) -> Self {
@@ -92,54 +103,127 @@ impl<'a> Wordle<'a> {
     }
 
     pub fn guess(&mut self, guessed_word: &str) -> WordValidation {
-        let words = &self.params.guess_wordlist;
-        let target_word = self.state.target_word.as_str();
-        let range = self.params.word_size;
-
-        if guessed_word.len() < range.0 || guessed_word.len() > range.1 {
-            return WordValidation::Invalid(
-                InvalidationReason::WrongLength,
-                guessed_word.to_string(),
-            );
-        } else if !words.contains(guessed_word) {
-            return WordValidation::Invalid(
-                InvalidationReason::UnknownWord,
-                guessed_word.to_string(),
-            );
-        } else if self
-            .state
-            .prev_guesses
-            .iter()
-            .any(|old_matches| old_matches.word == guessed_word)
-        {
-            return WordValidation::Invalid(
-                InvalidationReason::RepeatWord,
-                guessed_word.to_string(),
-            );
-        }
+        guess_board(&mut self.params, &mut self.state, guessed_word)
+    }
+}
+
+impl Game for Wordle {
+    fn params(&self) -> &GameParameters {
+        &self.params
+    }
+
+    fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    fn guess(&mut self, guessed_word: &str) -> WordValidation {
+        Wordle::guess(self, guessed_word)
+    }
+
+    fn undo(&mut self) -> bool {
+        Wordle::undo(self)
+    }
+
+    fn hint(&mut self, kind: HintKind) -> Option<Hint> {
+        Wordle::hint(self, kind)
+    }
 
-        let mut matches = match_word(target_word, guessed_word);
-        self.state.prev_guesses.push(matches.clone());
+    fn save(&self) -> Option<SavedGame> {
+        Some(Wordle::save(self))
+    }
 
-        if *target_word == *guessed_word {
-            return WordValidation::Valid(GuessResult::Correct, matches);
+    fn share(&self, options: &ShareOptions) -> Option<String> {
+        Some(Wordle::share(self, options))
+    }
+}
+
+pub fn guess_board(
+    params: &mut GameParameters,
+    state: &mut GameState,
+    guessed_word: &str,
+) -> WordValidation {
+    match state.update_status(params) {
+        GameStatus::Playing if state.paused => {
+            return WordValidation::Invalid(InvalidationReason::Paused, guessed_word.to_string())
         }
+        GameStatus::Playing => {}
+        status => return WordValidation::GameOver(status),
+    }
+
+    let target_word = state.target_word.as_str();
+    let language = params.language;
+    let canonical = if language.fold(guessed_word) == language.fold(target_word) {
+        Some(target_word.to_string())
+    } else {
+        params.find_word(guessed_word).map(|x| x.to_string())
+    };
+    let guessed_word = canonical.as_deref().unwrap_or(guessed_word);
+
+    if let Some(reason) = validate_guess(params, &state.displayed_guesses, guessed_word) {
+        return WordValidation::Invalid(reason, guessed_word.to_string());
+    } else if letter_count(guessed_word) != letter_count(target_word) {
+        return WordValidation::Invalid(InvalidationReason::WrongLength, guessed_word.to_string());
+    }
+
+    let matches = match_word_in(language, target_word, guessed_word);
+
+    if *target_word == *guessed_word {
+        state.push_guess(matches.clone(), matches.clone());
+        state.update_status(params);
+        return WordValidation::Valid(GuessResult::Correct, matches);
+    }
 
-        let mutator = &mut self.params.mutator;
+    let displayed = params.mutator.mutate(&RowContext {
+        guess: guessed_word,
+        target: target_word,
+        matches: &matches,
+        prev_rows: &state.prev_guesses,
+    });
+    state.push_guess(matches, displayed.clone());
+    state.update_status(params);
 
-        matches
-            .aligns
-            .iter_mut()
-            .for_each(|x| *x = mutator.mutate(*x));
+    WordValidation::Valid(GuessResult::Wrong, displayed)
+}
+
+pub fn validate_guess(
+    params: &GameParameters,
+    displayed_rows: &[WordMatch],
+    guessed_word: &str,
+) -> Option<InvalidationReason> {
+    let range = params.word_size;
+    let length = letter_count(guessed_word);
+
+    if length < range.0 || length > range.1 {
+        return Some(InvalidationReason::WrongLength);
+    } else if params.find_word(guessed_word).is_none() {
+        return Some(InvalidationReason::UnknownWord);
+    } else if displayed_rows
+        .iter()
+        .any(|old_matches| old_matches.word == guessed_word)
+    {
+        return Some(InvalidationReason::RepeatWord);
+    }
 
-        WordValidation::Valid(GuessResult::Wrong, matches)
+    if params.hard_mode {
+        if let Some(violation) = check_hard_mode(params.language, displayed_rows, guessed_word) {
+            return Some(InvalidationReason::HardMode(violation));
+        }
     }
+
+    None
 }
 
 pub fn match_word(target: &str, guess: &str) -> WordMatch {
-    let (mut target_used, mut matches): (Vec<bool>, Vec<_>) = target
-        .chars()
-        .zip(guess.chars())
+    match_word_in(Language::English, target, guess)
+}
+
+pub fn match_word_in(language: Language, target: &str, guess: &str) -> WordMatch {
+    let target_chars: Vec<char> = target.chars().map(|c| language.fold_char(c)).collect();
+    let guess_chars: Vec<char> = guess.chars().map(|c| language.fold_char(c)).collect();
+
+    let (mut target_used, mut matches): (Vec<bool>, Vec<_>) = target_chars
+        .iter()
+        .zip(guess_chars.iter())
         .map(|(tc, gc)| {
             if tc == gc {
                 (true, CharAlignment::Exact)
@@ -151,10 +235,14 @@ pub fn match_word(target: &str, guess: &str) -> WordMatch {
 
     matches
         .iter_mut()
-        .zip(guess.chars())
+        .zip(guess_chars.iter())
         .filter(|x| matches!(x.0, CharAlignment::NotFound))
         .for_each(|x| {
-            for (has_match, tc) in target_used.iter_mut().zip(target.chars()).filter(|x| !*x.0) {
+            for (has_match, tc) in target_used
+                .iter_mut()
+                .zip(target_chars.iter())
+                .filter(|x| !*x.0)
+            {
                 if x.1 == tc {
                     *has_match = true;
                     *x.0 = CharAlignment::Misplaced;
@@ -169,11 +257,62 @@ pub fn match_word(target: &str, guess: &str) -> WordMatch {
     }
 }
 
-impl Default for Wordle<'_> {
+impl Default for Wordle {
     fn default() -> Self {
-        let mut rng = thread_rng();
-        let params = GameParameters::default();
+        Self::new_seeded_game(GameParameters::default())
+    }
+}
 
-        Self::new_random_game(params, &mut rng)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::clock::ManualClock;
+    use std::sync::Arc;
+
+    fn timed_game(clock: &ManualClock) -> Wordle {
+        let params = GameParameters {
+            time_limit: Some(Duration::from_secs(60)),
+            clock: Arc::new(clock.clone()),
+            ..GameParameters::default()
+        };
+
+        Wordle::new_game(params, "crane".to_string())
+    }
+
+    #[test]
+    fn paused_games_reject_guesses_and_stop_the_clock() {
+        let clock = ManualClock::new();
+        let mut wordle = timed_game(&clock);
+
+        clock.advance(Duration::from_secs(10));
+        wordle.pause();
+        clock.advance(Duration::from_secs(100));
+
+        assert!(matches!(
+            wordle.guess("slate"),
+            WordValidation::Invalid(InvalidationReason::Paused, _)
+        ));
+        assert!(wordle.state.prev_guesses.is_empty());
+        assert_eq!(wordle.remaining_time(), Some(Duration::from_secs(50)));
+
+        wordle.unpause();
+        clock.advance(Duration::from_secs(5));
+
+        assert!(matches!(wordle.guess("slate"), WordValidation::Valid(..)));
+        assert_eq!(wordle.remaining_time(), Some(Duration::from_secs(45)));
+    }
+
+    #[test]
+    fn running_out_of_time_ends_the_game() {
+        let clock = ManualClock::new();
+        let mut wordle = timed_game(&clock);
+
+        clock.advance(Duration::from_secs(61));
+
+        assert_eq!(wordle.status(), GameStatus::Lost(LossReason::Timeout));
+        assert!(matches!(
+            wordle.guess("crane"),
+            WordValidation::GameOver(GameStatus::Lost(LossReason::Timeout))
+        ));
     }
 }
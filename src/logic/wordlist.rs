@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use super::language::letter_count;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordList {
+    words: Vec<Arc<str>>,
+    metadata: HashMap<Arc<str>, Vec<String>>,
+}
+
+impl WordList {
+    pub fn new(words: impl IntoIterator<Item = impl Into<Arc<str>>>) -> Self {
+        Self {
+            words: words.into_iter().map(|x| x.into()).collect(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Self {
+        Self::parse_sized(text, (0, usize::MAX))
+    }
+
+    pub fn parse_sized(text: &str, word_size: (usize, usize)) -> Self {
+        let mut list = Self::default();
+
+        for line in text.lines() {
+            list.push_line(line, word_size);
+        }
+
+        list
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut list = Self::default();
+
+        for line in reader.lines() {
+            list.push_line(&line?, (0, usize::MAX));
+        }
+
+        Ok(list)
+    }
+
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_source(source: &str) -> Result<Self> {
+        match source {
+            "-" => Self::from_reader(stdin().lock()),
+            path => Self::from_path(path),
+        }
+    }
+
+    fn push_line(&mut self, line: &str, word_size: (usize, usize)) {
+        if !line.is_empty() && line.bytes().all(|x| x.is_ascii_alphabetic()) {
+            if line.len() >= word_size.0 && line.len() <= word_size.1 {
+                self.words.push(line.into());
+            }
+            return;
+        }
+
+        let line = match line.split_once('#') {
+            Some((content, _)) => content,
+            None => line,
+        };
+
+        let mut columns = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|x| !x.is_empty());
+        let word = match columns.next() {
+            Some(word) if in_range(word, word_size) => Arc::<str>::from(word),
+            _ => return,
+        };
+
+        let metadata: Vec<String> = columns.map(|x| x.to_string()).collect();
+        if !metadata.is_empty() {
+            self.metadata.insert(word.clone(), metadata);
+        }
+
+        self.words.push(word);
+    }
+
+    pub fn words(&self) -> &[Arc<str>] {
+        &self.words
+    }
+
+    pub fn metadata(&self, word: &str) -> Option<&[String]> {
+        self.metadata.get(word).map(|x| x.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.words.iter().map(|x| x.as_ref())
+    }
+
+    pub fn filter_size(&self, word_size: (usize, usize)) -> Self {
+        let words: Vec<Arc<str>> = self
+            .words
+            .iter()
+            .filter(|x| in_range(x, word_size))
+            .cloned()
+            .collect();
+
+        let metadata = words
+            .iter()
+            .filter_map(|x| Some((x.clone(), self.metadata.get(x)?.clone())))
+            .collect();
+
+        Self { words, metadata }
+    }
+}
+
+fn in_range(word: &str, word_size: (usize, usize)) -> bool {
+    let length = letter_count(word);
+    length >= word_size.0 && length <= word_size.1
+}
@@ -1,24 +1,23 @@
-use super::{
-    filters::{FilterCriteria, PosFilterCriteria},
-    solvers::{Guess, Solver, SolverWordList},
-};
-use crate::logic::{types::WordMatch, wordle::match_word, CharAlignment};
+use super::solvers::{Guess, Solver, SolverWordList};
+use crate::logic::{types::WordMatch, wordle::match_word, CharAlignment, Knowledge};
 use rustc_hash::FxHashMap;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-type MatchMap<'a> = FxHashMap<(&'a str, &'a str), WordMatch>;
-type DistMap<'a> = FxHashMap<&'a str, HashMap<Vec<CharAlignment>, u32>>;
+type MatchMap = FxHashMap<(Arc<str>, Arc<str>), WordMatch>;
+type DistMap = FxHashMap<Arc<str>, HashMap<Vec<CharAlignment>, u32>>;
 
 #[derive(Debug)]
-pub struct EntropySolver<'a> {
-    dist_map: DistMap<'a>,
-    wordlist: SolverWordList<'a>,
+pub struct EntropySolver {
+    dist_map: DistMap,
+    wordlist: SolverWordList,
 
     prev_guesses: Vec<WordMatch>,
+    knowledge: Knowledge,
 }
 
-impl<'a> EntropySolver<'a> {
-    pub fn new(base_wordlist: &SolverWordList<'a>) -> Self {
+impl EntropySolver {
+    pub fn new(base_wordlist: &SolverWordList) -> Self {
         let match_map = create_match_map(base_wordlist);
         let dist_map = create_dist_map(base_wordlist, &match_map);
 
@@ -26,14 +25,16 @@ impl<'a> EntropySolver<'a> {
             dist_map,
             wordlist: base_wordlist.clone(),
             prev_guesses: Vec::new(),
+            knowledge: Knowledge::new(),
         }
     }
 }
 
-impl<'a> Solver<'a> for EntropySolver<'a> {
-    fn reload_wordlist(&mut self, wordlist: &SolverWordList<'a>) {
+impl Solver for EntropySolver {
+    fn reload_wordlist(&mut self, wordlist: &SolverWordList) {
         self.wordlist = wordlist.clone();
         self.prev_guesses = Vec::new();
+        self.knowledge = Knowledge::new();
     }
 
     fn guess(&self) -> Option<Guess> {
@@ -46,7 +47,7 @@ impl<'a> Solver<'a> for EntropySolver<'a> {
             .wordlist
             .iter()
             .filter_map(|guess_word| {
-                if prev_guess_strs.contains(guess_word) {
+                if prev_guess_strs.contains(&guess_word.as_ref()) {
                     return None;
                 }
 
@@ -57,11 +58,10 @@ impl<'a> Solver<'a> for EntropySolver<'a> {
                         let alignment: &Vec<CharAlignment> = &x.aligns;
                         self.dist_map
                             .get(guess_word)
-                            .map(|map| {
+                            .and_then(|map| {
                                 let local_matches = map.get(alignment)?;
                                 Some((word_total / *local_matches as f32).log2())
                             })
-                            .flatten()
                             .unwrap_or(0.0)
                     })
                     .sum();
@@ -100,30 +100,38 @@ impl<'a> Solver<'a> for EntropySolver<'a> {
     }
 
     fn narrow_words(&mut self, guess_result: &WordMatch) {
+        self.knowledge.add(guess_result);
         self.prev_guesses.push(guess_result.clone());
     }
+
+    fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
 }
 
 #[derive(Debug)]
-pub struct GlobalShrinkingEntropySolver<'a> {
-    match_map: MatchMap<'a>,
-    wordlist: SolverWordList<'a>,
+pub struct GlobalShrinkingEntropySolver {
+    match_map: MatchMap,
+    wordlist: SolverWordList,
+    knowledge: Knowledge,
 }
 
-impl<'a> GlobalShrinkingEntropySolver<'a> {
-    pub fn new(base_wordlist: &SolverWordList<'a>) -> Self {
+impl GlobalShrinkingEntropySolver {
+    pub fn new(base_wordlist: &SolverWordList) -> Self {
         let match_map = create_match_map(base_wordlist);
 
         Self {
             match_map,
             wordlist: base_wordlist.clone(),
+            knowledge: Knowledge::new(),
         }
     }
 }
 
-impl<'a> Solver<'a> for GlobalShrinkingEntropySolver<'a> {
-    fn reload_wordlist(&mut self, wordlist: &SolverWordList<'a>) {
+impl Solver for GlobalShrinkingEntropySolver {
+    fn reload_wordlist(&mut self, wordlist: &SolverWordList) {
         self.wordlist = wordlist.clone();
+        self.knowledge = Knowledge::new();
     }
 
     fn guess(&self) -> Option<Guess> {
@@ -131,7 +139,7 @@ impl<'a> Solver<'a> for GlobalShrinkingEntropySolver<'a> {
         let guess = self
             .wordlist
             .iter()
-            .filter_map(|guess_word| {
+            .map(|guess_word| {
                 let word_dist = create_word_dist(guess_word, &self.wordlist, &self.match_map);
                 let guess_estimated_entropy: f32 = word_dist
                     .values()
@@ -143,7 +151,7 @@ impl<'a> Solver<'a> for GlobalShrinkingEntropySolver<'a> {
                     })
                     .sum();
 
-                Some((guess_word, guess_estimated_entropy))
+                (guess_word, guess_estimated_entropy)
             })
             .reduce(|best_guess, current_guess| {
                 if best_guess.1 >= current_guess.1 {
@@ -158,38 +166,40 @@ impl<'a> Solver<'a> for GlobalShrinkingEntropySolver<'a> {
     }
 
     fn narrow_words(&mut self, guess_result: &WordMatch) {
-        let filter = PosFilterCriteria::from_matches(guess_result);
-        let new_words: SolverWordList = self
-            .wordlist
-            .iter()
-            .filter_map(|word| Some(*word).filter(|x| filter.check(x)))
-            .collect();
+        self.knowledge.add(guess_result);
+        self.wordlist
+            .retain(|word| self.knowledge.is_consistent(word));
+    }
 
-        self.wordlist = new_words
+    fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
     }
 }
 
-fn create_match_map<'a>(wordlist: &SolverWordList<'a>) -> MatchMap<'a> {
+fn create_match_map(wordlist: &SolverWordList) -> MatchMap {
     let mut map = FxHashMap::default();
     for target_word in wordlist {
         for guess_word in wordlist {
             let word_match = match_word(target_word, guess_word);
-            map.insert((*target_word, *guess_word), word_match);
+            map.insert((target_word.clone(), guess_word.clone()), word_match);
         }
     }
 
     map
 }
 
-fn create_word_dist<'a>(
-    word: &'a str,
-    wordlist: &SolverWordList<'a>,
-    match_map: &MatchMap<'a>,
+fn create_word_dist(
+    word: &Arc<str>,
+    wordlist: &SolverWordList,
+    match_map: &MatchMap,
 ) -> HashMap<Vec<CharAlignment>, u32> {
     let mut word_dist = HashMap::new();
 
     for guess_word in wordlist {
-        let word_match = match_map.get(&(word, *guess_word)).unwrap().clone();
+        let word_match = match_map
+            .get(&(word.clone(), guess_word.clone()))
+            .unwrap()
+            .clone();
         let alignments = word_match.aligns;
 
         *word_dist.entry(alignments).or_insert(0) += 1_u32;
@@ -198,28 +208,29 @@ fn create_word_dist<'a>(
     word_dist
 }
 
-fn create_dist_map<'a>(wordlist: &SolverWordList<'a>, match_map: &MatchMap<'a>) -> DistMap<'a> {
+fn create_dist_map(wordlist: &SolverWordList, match_map: &MatchMap) -> DistMap {
     let mut dist_map = DistMap::default();
 
     for word in wordlist {
         let local_dist = create_word_dist(word, wordlist, match_map);
 
-        dist_map.insert(*word, local_dist);
+        dist_map.insert(word.clone(), local_dist);
     }
 
     dist_map
 }
 
 #[derive(Debug)]
-pub struct GlobalFilteredEntropySolver<'a> {
-    match_map: MatchMap<'a>,
-    wordlist: SolverWordList<'a>,
-    filtered_list: SolverWordList<'a>,
+pub struct GlobalFilteredEntropySolver {
+    match_map: MatchMap,
+    wordlist: SolverWordList,
+    filtered_list: SolverWordList,
     prev_guesses: Vec<String>,
+    knowledge: Knowledge,
 }
 
-impl<'a> GlobalFilteredEntropySolver<'a> {
-    pub fn new(base_wordlist: &SolverWordList<'a>) -> Self {
+impl GlobalFilteredEntropySolver {
+    pub fn new(base_wordlist: &SolverWordList) -> Self {
         let match_map = create_match_map(base_wordlist);
 
         Self {
@@ -227,14 +238,16 @@ impl<'a> GlobalFilteredEntropySolver<'a> {
             wordlist: base_wordlist.clone(),
             filtered_list: base_wordlist.clone(),
             prev_guesses: Vec::default(),
+            knowledge: Knowledge::new(),
         }
     }
 }
 
-impl<'a> Solver<'a> for GlobalFilteredEntropySolver<'a> {
-    fn reload_wordlist(&mut self, wordlist: &SolverWordList<'a>) {
+impl Solver for GlobalFilteredEntropySolver {
+    fn reload_wordlist(&mut self, wordlist: &SolverWordList) {
         self.filtered_list = wordlist.clone();
         self.prev_guesses = Vec::default();
+        self.knowledge = Knowledge::new();
     }
 
     fn guess(&self) -> Option<Guess> {
@@ -248,7 +261,7 @@ impl<'a> Solver<'a> for GlobalFilteredEntropySolver<'a> {
             .filter_map(|guess_word| {
                 let word_dist = create_word_dist(guess_word, &self.filtered_list, &self.match_map);
 
-                if prev_guess_strs.contains(guess_word) {
+                if prev_guess_strs.contains(&guess_word.as_ref()) {
                     return None;
                 }
 
@@ -288,14 +301,14 @@ impl<'a> Solver<'a> for GlobalFilteredEntropySolver<'a> {
     }
 
     fn narrow_words(&mut self, guess_result: &WordMatch) {
-        let filter = PosFilterCriteria::from_matches(guess_result);
-        let new_words: SolverWordList = self
-            .filtered_list
-            .iter()
-            .filter_map(|word| Some(*word).filter(|x| filter.check(x)))
-            .collect();
+        self.knowledge.add(guess_result);
+        self.filtered_list
+            .retain(|word| self.knowledge.is_consistent(word));
 
         self.prev_guesses.push(guess_result.word.clone());
-        self.filtered_list = new_words
+    }
+
+    fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
     }
 }
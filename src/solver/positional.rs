@@ -1,24 +1,27 @@
-use super::filters::{FilterCriteria, PosFilterCriteria};
 use super::solvers::{Guess, Solver, SolverWordList};
-use crate::logic::WordMatch;
+use crate::logic::{Knowledge, WordMatch};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Debug)]
-pub struct PositionalSolver<'a> {
-    wordlist: SolverWordList<'a>,
+pub struct PositionalSolver {
+    wordlist: SolverWordList,
+    knowledge: Knowledge,
 }
 
-impl<'a> PositionalSolver<'a> {
-    pub fn new(wordlist: &SolverWordList<'a>) -> Self {
+impl PositionalSolver {
+    pub fn new(wordlist: &SolverWordList) -> Self {
         Self {
             wordlist: wordlist.clone(),
+            knowledge: Knowledge::new(),
         }
     }
 }
 
-impl<'a> Solver<'a> for PositionalSolver<'a> {
-    fn reload_wordlist(&mut self, wordlist: &SolverWordList<'a>) {
+impl Solver for PositionalSolver {
+    fn reload_wordlist(&mut self, wordlist: &SolverWordList) {
         self.wordlist = wordlist.clone();
+        self.knowledge = Knowledge::new();
     }
 
     fn guess(&self) -> Option<Guess> {
@@ -33,14 +36,13 @@ impl<'a> Solver<'a> for PositionalSolver<'a> {
     }
 
     fn narrow_words(&mut self, guess_result: &WordMatch) {
-        let filter = PosFilterCriteria::from_matches(guess_result);
-        let new_words: SolverWordList = self
-            .wordlist
-            .iter()
-            .filter_map(|word| Some(*word).filter(|x| filter.check(x)))
-            .collect();
+        self.knowledge.add(guess_result);
+        self.wordlist
+            .retain(|word| self.knowledge.is_consistent(word));
+    }
 
-        self.wordlist = new_words
+    fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
     }
 }
 
@@ -68,8 +70,12 @@ pub fn score_word(word: &str, letter_freq: &PosLetterFreq) -> f32 {
     final_score
 }
 
-pub fn count_letter(word_list: &Vec<&'_ str>) -> PosLetterFreq {
-    let max_size = word_list.iter().map(|x| x.len()).max().unwrap_or(0);
+pub fn count_letter(word_list: &[Arc<str>]) -> PosLetterFreq {
+    let max_size = word_list
+        .iter()
+        .map(|x| x.chars().count())
+        .max()
+        .unwrap_or(0);
 
     let mut pos_count: Vec<LetterCount> = (0..max_size).map(|_| LetterCount::new()).collect();
     let mut tot_count: LetterCount = LetterCount::new();
@@ -1,16 +1,18 @@
-use crate::logic::WordMatch;
+use crate::logic::{Knowledge, WordMatch};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 pub type Guess = String;
-pub type SolverWordList<'a> = Vec<&'a str>;
+pub type SolverWordList = Vec<Arc<str>>;
 
-pub trait Solver<'a>
+pub trait Solver
 where
     Self: Debug,
 {
-    fn reload_wordlist(&mut self, wordlist: &SolverWordList<'a>);
+    fn reload_wordlist(&mut self, wordlist: &SolverWordList);
     fn guess(&self) -> Option<Guess>;
     fn narrow_words(&mut self, guess_result: &WordMatch);
+    fn knowledge(&self) -> &Knowledge;
 
     fn narrow_then_guess(&mut self, guess_result: &WordMatch) -> Option<Guess> {
         self.narrow_words(guess_result);